@@ -192,11 +192,12 @@ impl Parser {
                     TokenKind::SelfObject => {
                         parser.import_alias(TokenKind::Identifier)?
                     }
+                    TokenKind::Mul => None,
                     _ => {
                         error!(
                             token.location,
-                            "expected an identifier, constant, \
-                             or 'self'; found '{}' instead",
+                            "expected an identifier, constant, 'self' \
+                             or '*', found '{}' instead",
                             token.value
                         );
                     }
@@ -3502,6 +3503,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_imports_with_wildcard() {
+        assert_eq!(
+            top(parse("import foo (*)")),
+            TopLevelExpression::Import(Box::new(Import {
+                path: ImportPath {
+                    steps: vec![Identifier {
+                        name: "foo".to_string(),
+                        location: cols(8, 10)
+                    }],
+                    location: cols(8, 10)
+                },
+                symbols: Some(ImportSymbols {
+                    values: vec![ImportSymbol {
+                        name: "*".to_string(),
+                        alias: None,
+                        location: cols(13, 13)
+                    }],
+                    location: cols(12, 14)
+                }),
+                tags: None,
+                include: true,
+                location: cols(1, 14)
+            }))
+        );
+    }
+
     #[test]
     fn test_imports_with_aliases() {
         assert_eq!(
@@ -37,6 +37,7 @@ pub(crate) enum DiagnosticId {
     MissingMain,
     MissingTrait,
     Moved,
+    RedundantImport,
     Unreachable,
     UnusedSymbol,
     UnusedResult,
@@ -67,6 +68,7 @@ impl fmt::Display for DiagnosticId {
             DiagnosticId::InvalidCast => "invalid-cast",
             DiagnosticId::UnusedSymbol => "unused-symbol",
             DiagnosticId::UnusedResult => "unused-result",
+            DiagnosticId::RedundantImport => "redundant-import",
         };
 
         write!(f, "{}", id)
@@ -102,6 +104,10 @@ pub(crate) struct Diagnostic {
     message: String,
     file: PathBuf,
     location: Location,
+
+    /// The source range that can be removed to resolve the diagnostic, such as
+    /// the range of an unused import.
+    removable: Option<Location>,
 }
 
 impl Diagnostic {
@@ -112,7 +118,7 @@ impl Diagnostic {
         file: PathBuf,
         location: Location,
     ) -> Self {
-        Self { kind, id, message, file, location }
+        Self { kind, id, message, file, location, removable: None }
     }
 
     pub(crate) fn is_error(&self) -> bool {
@@ -138,6 +144,10 @@ impl Diagnostic {
     pub(crate) fn location(&self) -> &Location {
         &self.location
     }
+
+    pub(crate) fn removable(&self) -> Option<&Location> {
+        self.removable.as_ref()
+    }
 }
 
 /// A collection of warnings and errors.
@@ -209,6 +219,25 @@ impl Diagnostics {
         );
     }
 
+    pub(crate) fn undefined_imported_symbol(
+        &mut self,
+        name: &str,
+        suggestion: Option<&str>,
+        file: PathBuf,
+        location: Location,
+    ) {
+        let message = if let Some(similar) = suggestion {
+            format!(
+                "the symbol '{}' is undefined, did you mean '{}'?",
+                name, similar
+            )
+        } else {
+            format!("the symbol '{}' is undefined", name)
+        };
+
+        self.error(DiagnosticId::InvalidSymbol, message, file, location);
+    }
+
     pub(crate) fn undefined_field(
         &mut self,
         name: &str,
@@ -1099,6 +1128,43 @@ impl Diagnostics {
         );
     }
 
+    pub(crate) fn unused_import(
+        &mut self,
+        name: &str,
+        file: PathBuf,
+        location: Location,
+        removable: Location,
+    ) {
+        let mut diag = Diagnostic::new(
+            DiagnosticType::Warning,
+            DiagnosticId::UnusedSymbol,
+            format!("the symbol '{}' is unused", name),
+            file,
+            location,
+        );
+
+        diag.removable = Some(removable);
+        self.values.push(diag);
+    }
+
+    pub(crate) fn redundant_module_alias(
+        &mut self,
+        name: &str,
+        file: PathBuf,
+        location: Location,
+    ) {
+        self.warn(
+            DiagnosticId::RedundantImport,
+            format!(
+                "the alias '{}' is redundant, as modules are imported using \
+                their name by default",
+                name
+            ),
+            file,
+            location,
+        );
+    }
+
     pub(crate) fn invalid_inline_method(
         &mut self,
         file: PathBuf,
@@ -302,9 +302,9 @@ impl<'ctx> Layouts<'ctx> {
                 // Mutexes are smaller on Linux, resulting in a smaller process
                 // size, so we have to take that into account when calculating
                 // field offsets.
-                104
+                120
             }
-            _ => 120,
+            _ => 136,
         };
 
         // The size of the data of a process that isn't exposed to the generated
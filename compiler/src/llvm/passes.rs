@@ -1771,6 +1771,103 @@ impl<'shared, 'module, 'ctx> LowerMethod<'shared, 'module, 'ctx> {
 
                         self.builder.store(reg_var, res);
                     }
+                    Intrinsic::IntCountOnes => {
+                        let reg_var = self.variables[&ins.register];
+                        let val_var = self.variables[&ins.arguments[0]];
+                        let val = self.builder.load_int(val_var).into();
+                        let func = self.module.intrinsic(
+                            "llvm.ctpop",
+                            &[self.builder.context.i64_type().into()],
+                        );
+                        let res = self
+                            .builder
+                            .call_with_return(func, &[val])
+                            .into_int_value();
+
+                        self.builder.store(reg_var, res);
+                    }
+                    Intrinsic::FloatSqrt => {
+                        let reg_var = self.variables[&ins.register];
+                        let val_var = self.variables[&ins.arguments[0]];
+                        let val = self.builder.load_float(val_var);
+                        let func = self.module.intrinsic(
+                            "llvm.sqrt",
+                            &[self.builder.context.f64_type().into()],
+                        );
+
+                        let res = self
+                            .builder
+                            .call_with_return(func, &[val.into()])
+                            .into_float_value();
+
+                        self.builder.store(reg_var, res);
+                    }
+                    Intrinsic::FloatPow => {
+                        let reg_var = self.variables[&ins.register];
+                        let lhs_var = self.variables[&ins.arguments[0]];
+                        let rhs_var = self.variables[&ins.arguments[1]];
+                        let lhs = self.builder.load_float(lhs_var);
+                        let rhs = self.builder.load_float(rhs_var);
+                        let func = self.module.intrinsic(
+                            "llvm.pow",
+                            &[self.builder.context.f64_type().into()],
+                        );
+
+                        let res = self
+                            .builder
+                            .call_with_return(func, &[lhs.into(), rhs.into()])
+                            .into_float_value();
+
+                        self.builder.store(reg_var, res);
+                    }
+                    Intrinsic::FloatSin => {
+                        let reg_var = self.variables[&ins.register];
+                        let val_var = self.variables[&ins.arguments[0]];
+                        let val = self.builder.load_float(val_var);
+                        let func = self.module.intrinsic(
+                            "llvm.sin",
+                            &[self.builder.context.f64_type().into()],
+                        );
+
+                        let res = self
+                            .builder
+                            .call_with_return(func, &[val.into()])
+                            .into_float_value();
+
+                        self.builder.store(reg_var, res);
+                    }
+                    Intrinsic::FloatCos => {
+                        let reg_var = self.variables[&ins.register];
+                        let val_var = self.variables[&ins.arguments[0]];
+                        let val = self.builder.load_float(val_var);
+                        let func = self.module.intrinsic(
+                            "llvm.cos",
+                            &[self.builder.context.f64_type().into()],
+                        );
+
+                        let res = self
+                            .builder
+                            .call_with_return(func, &[val.into()])
+                            .into_float_value();
+
+                        self.builder.store(reg_var, res);
+                    }
+                    Intrinsic::FloatLog => {
+                        let reg_var = self.variables[&ins.register];
+                        let val_var = self.variables[&ins.arguments[0]];
+                        let val = self.builder.load_float(val_var);
+                        let func = self.module.intrinsic(
+                            "llvm.log",
+                            &[self.builder.context.f64_type().into()],
+                        );
+
+                        let res = self
+                            .builder
+                            .call_with_return(func, &[val.into()])
+                            .into_float_value();
+
+                        self.builder.store(reg_var, res);
+                    }
                     Intrinsic::Moved
                     | Intrinsic::RefMove
                     | Intrinsic::MutMove => unreachable!(),
@@ -2676,6 +2773,36 @@ impl<'shared, 'module, 'ctx> LowerMethod<'shared, 'module, 'ctx> {
         self.method.registers.value_type(register)
     }
 
+    /// Applies C's default argument promotions to an argument passed to the
+    /// variadic part of a function.
+    ///
+    /// C promotes floats to doubles, and integers smaller than an int to an
+    /// int. Functions such as printf() expect their arguments to be promoted,
+    /// so we have to do the same.
+    fn promote_variadic_argument(
+        &self,
+        register: RegisterId,
+        value: BasicValueEnum<'ctx>,
+    ) -> BasicValueEnum<'ctx> {
+        match value {
+            BasicValueEnum::FloatValue(v)
+                if v.get_type() == self.builder.context.f32_type() =>
+            {
+                self.builder.float_to_float(v, 64).into()
+            }
+            BasicValueEnum::IntValue(v)
+                if v.get_type().get_bit_width() < 32 =>
+            {
+                let signed = self
+                    .register_type(register)
+                    .is_signed_int(&self.shared.state.db);
+
+                self.builder.int_to_int(v, 32, signed).into()
+            }
+            v => v,
+        }
+    }
+
     fn call(
         &self,
         kind: CallKind<'ctx>,
@@ -2750,7 +2877,9 @@ impl<'shared, 'module, 'ctx> LowerMethod<'shared, 'module, 'ctx> {
                 None => {
                     // We may run into this case when calling a variadic
                     // function and passing more arguments than are defined.
-                    args.push(self.builder.load(typ, var).into());
+                    let val = self.builder.load(typ, var);
+
+                    args.push(self.promote_variadic_argument(*reg, val).into());
                 }
             }
         }
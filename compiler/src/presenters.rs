@@ -102,9 +102,20 @@ impl JsonPresenter {
 
     fn to_json(&self, diagnostic: &Diagnostic) -> String {
         let loc = diagnostic.location();
+        let removable = if let Some(loc) = diagnostic.removable() {
+            format!(
+                ", \"removable\": {{\"lines\": [{}, {}], \"columns\": [{}, {}]}}",
+                loc.line_start,
+                loc.line_end,
+                loc.column_start,
+                loc.column_end,
+            )
+        } else {
+            String::new()
+        };
 
         format!(
-            "{{\"id\": {:?}, \"level\": {:?}, \"file\": {:?}, \"lines\": [{}, {}], \"columns\": [{}, {}], \"message\": {:?}}}",
+            "{{\"id\": {:?}, \"level\": {:?}, \"file\": {:?}, \"lines\": [{}, {}], \"columns\": [{}, {}], \"message\": {:?}{}}}",
             diagnostic.id().to_string(),
             diagnostic.kind().to_string(),
             diagnostic.file().to_string_lossy(),
@@ -112,7 +123,8 @@ impl JsonPresenter {
             loc.line_end,
             loc.column_start,
             loc.column_end,
-            diagnostic.message()
+            diagnostic.message(),
+            removable
         )
     }
 }
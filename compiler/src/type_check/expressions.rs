@@ -1111,9 +1111,11 @@ impl<'a> CheckConstant<'a> {
     fn constant(&mut self, node: &mut hir::ConstantRef) -> TypeRef {
         let name = &node.name;
         let symbol = if let Some(src) = node.source.as_ref() {
-            if let Some(Symbol::Module(module)) =
-                self.module.use_symbol(self.db_mut(), &src.name)
-            {
+            if let Some(Symbol::Module(module)) = self.module.use_symbol_at(
+                self.db_mut(),
+                &src.name,
+                src.location,
+            ) {
                 module.use_symbol(self.db_mut(), name)
             } else {
                 self.state.diagnostics.symbol_not_a_module(
@@ -1125,7 +1127,7 @@ impl<'a> CheckConstant<'a> {
                 return TypeRef::Error;
             }
         } else {
-            self.module.use_symbol(self.db_mut(), name)
+            self.module.use_symbol_at(self.db_mut(), name, node.location)
         };
 
         match symbol {
@@ -1839,7 +1841,8 @@ impl<'a> CheckMethodBody<'a> {
             return;
         }
 
-        let symbol = self.lookup_constant(name, node.source.as_ref());
+        let symbol =
+            self.lookup_constant(name, node.source.as_ref(), node.location);
         let exp_type = match symbol {
             Ok(Some(Symbol::Constant(id))) => {
                 let typ = id.value_type(self.db());
@@ -2503,7 +2506,11 @@ impl<'a> CheckMethodBody<'a> {
 
                     return TypeRef::Error;
                 }
-                _ => match module.use_symbol(self.db_mut(), &node.name) {
+                _ => match module.use_symbol_at(
+                    self.db_mut(),
+                    &node.name,
+                    node.location,
+                ) {
                     Some(Symbol::Constant(id)) => {
                         node.resolved_type = id.value_type(self.db());
                         node.kind = ConstantKind::Constant(id);
@@ -2633,7 +2640,7 @@ impl<'a> CheckMethodBody<'a> {
                 }
                 _ => {
                     if let Some(Symbol::Module(id)) =
-                        module.use_symbol(self.db_mut(), name)
+                        module.use_symbol_at(self.db_mut(), name, node.location)
                     {
                         if !receiver {
                             self.state.diagnostics.symbol_not_a_value(
@@ -2649,7 +2656,7 @@ impl<'a> CheckMethodBody<'a> {
                     }
 
                     if let Some(Symbol::Method(method)) =
-                        module.use_symbol(self.db_mut(), name)
+                        module.use_symbol_at(self.db_mut(), name, node.location)
                     {
                         let id = method.module(self.db());
 
@@ -3835,7 +3842,11 @@ impl<'a> CheckMethodBody<'a> {
                         }
                     }
 
-                    match self.module.use_symbol(self.db_mut(), name) {
+                    match self.module.use_symbol_at(
+                        self.db_mut(),
+                        name,
+                        node.location,
+                    ) {
                         Some(Symbol::Method(method)) => {
                             // The receiver of imported module methods is the
                             // module they are defined in.
@@ -4327,11 +4338,14 @@ impl<'a> CheckMethodBody<'a> {
         &mut self,
         name: &str,
         source: Option<&hir::Identifier>,
+        location: Location,
     ) -> Result<Option<Symbol>, ()> {
         if let Some(src) = source {
-            if let Some(Symbol::Module(module)) =
-                self.module.use_symbol(self.db_mut(), &src.name)
-            {
+            if let Some(Symbol::Module(module)) = self.module.use_symbol_at(
+                self.db_mut(),
+                &src.name,
+                src.location,
+            ) {
                 Ok(module.use_symbol(self.db_mut(), name))
             } else {
                 self.state.diagnostics.symbol_not_a_module(
@@ -4343,7 +4357,7 @@ impl<'a> CheckMethodBody<'a> {
                 Err(())
             }
         } else {
-            Ok(self.module.use_symbol(self.db_mut(), name))
+            Ok(self.module.use_symbol_at(self.db_mut(), name, location))
         }
     }
 
@@ -5,7 +5,34 @@ use crate::state::State;
 use location::Location;
 use std::path::PathBuf;
 use types::module_name::ModuleName;
-use types::{Database, ModuleId, Symbol, IMPORT_MODULE_ITSELF_NAME};
+use types::{
+    Database, ModuleId, Symbol, IMPORT_ALL_NAME, IMPORT_MODULE_ITSELF_NAME,
+};
+
+/// The maximum edit distance between an undefined symbol and a symbol to
+/// suggest in its place.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Returns the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+
+    for (i, ac) in a.chars().enumerate() {
+        cur[0] = i + 1;
+
+        for (j, &bc) in b.iter().enumerate() {
+            let cost = if ac == bc { 0 } else { 1 };
+
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
 
 /// A compiler pass that defines any imported types.
 ///
@@ -55,12 +82,22 @@ impl<'a> DefineImportedTypes<'a> {
                 let import_as = symbol.import_as.name.clone();
 
                 if name == IMPORT_MODULE_ITSELF_NAME {
+                    if import_as == source_name.tail() {
+                        self.state.diagnostics.redundant_module_alias(
+                            &import_as,
+                            self.file(),
+                            symbol.import_as.location,
+                        );
+                    }
+
                     self.import_module(
                         source,
                         &source_name,
                         import_as,
                         symbol.import_as.location,
                     );
+                } else if name == IMPORT_ALL_NAME {
+                    self.import_all(source, symbol.name.location);
                 } else {
                     self.import_symbol(source, symbol);
                 }
@@ -125,14 +162,53 @@ impl<'a> DefineImportedTypes<'a> {
                 );
             }
         } else {
-            self.state.diagnostics.undefined_symbol(
+            let similar = self.similar_symbol(source, name);
+
+            self.state.diagnostics.undefined_imported_symbol(
                 name,
+                similar.as_deref(),
                 self.file(),
                 node.name.location,
             );
         }
     }
 
+    /// Returns the name of the symbol in `source` that's the most similar to
+    /// `name`, if any.
+    fn similar_symbol(&self, source: ModuleId, name: &str) -> Option<String> {
+        let len = name.chars().count();
+
+        source
+            .importable_symbols(self.db())
+            .into_iter()
+            .filter(|(_, sym)| sym.is_visible_to(self.db(), self.module))
+            .map(|(sym_name, _)| (edit_distance(name, &sym_name), sym_name))
+            .filter(|&(dist, _)| dist <= MAX_SUGGESTION_DISTANCE && dist < len)
+            .min_by_key(|&(dist, _)| dist)
+            .map(|(_, sym_name)| sym_name)
+    }
+
+    fn import_all(&mut self, source: ModuleId, location: Location) {
+        for (name, symbol) in source.importable_symbols(self.db()) {
+            // Private symbols are skipped instead of producing an error, as
+            // otherwise a wildcard import of a module with private symbols
+            // would always fail.
+            if !symbol.is_visible_to(self.db(), self.module) {
+                continue;
+            }
+
+            if self.module.symbol_exists(self.db(), &name) {
+                self.state.diagnostics.duplicate_symbol(
+                    &name,
+                    self.file(),
+                    location,
+                );
+            } else {
+                self.module.new_symbol(self.db_mut(), name, symbol);
+            }
+        }
+    }
+
     fn file(&self) -> PathBuf {
         self.module.file(self.db())
     }
@@ -195,19 +271,28 @@ pub(crate) fn check_unused_imports(
 
             let tail = &import.source.last().unwrap().name;
 
+            let file = mod_id.file(&state.db);
+
             if import.symbols.is_empty() {
                 if mod_id.symbol_is_used(&state.db, tail) {
                     continue;
                 }
 
-                let file = mod_id.file(&state.db);
                 let loc = import.location;
 
-                state.diagnostics.unused_symbol(tail, file, loc);
+                state.diagnostics.unused_import(tail, file, loc, loc);
             } else {
-                for sym in &import.symbols {
+                let mut unused = Vec::new();
+
+                for (index, sym) in import.symbols.iter().enumerate() {
                     let mut name = &sym.import_as.name;
 
+                    // Wildcard imports may pull in symbols that are never
+                    // used, so we don't report these.
+                    if name == IMPORT_ALL_NAME {
+                        continue;
+                    }
+
                     if name == IMPORT_MODULE_ITSELF_NAME {
                         name = tail;
                     }
@@ -218,10 +303,27 @@ pub(crate) fn check_unused_imports(
                         continue;
                     }
 
-                    let file = mod_id.file(&state.db);
-                    let loc = sym.location;
+                    unused.push((name, index));
+                }
 
-                    state.diagnostics.unused_symbol(name, file, loc);
+                // If all symbols are unused the entire import can be removed,
+                // otherwise only the unused symbols can be removed.
+                let all = unused.len() == import.symbols.len();
+
+                for (name, index) in unused {
+                    let loc = import.symbols[index].location;
+                    let remove = if all {
+                        import.location
+                    } else {
+                        removable_symbol_range(&import.symbols, index)
+                    };
+
+                    state.diagnostics.unused_import(
+                        name,
+                        file.clone(),
+                        loc,
+                        remove,
+                    );
                 }
             }
         }
@@ -230,6 +332,39 @@ pub(crate) fn check_unused_imports(
     !state.diagnostics.has_errors()
 }
 
+/// Returns the range to remove when only the imported symbol at the given index
+/// is unused.
+///
+/// The range includes the comma (and any whitespace) that separates the symbol
+/// from its neighbour, such that removing it leaves behind a valid import. For
+/// all but the last symbol this is the range up to the start of the next
+/// symbol, while for the last symbol it's the range starting at the end of the
+/// previous symbol.
+fn removable_symbol_range(
+    symbols: &[hir::ImportSymbol],
+    index: usize,
+) -> Location {
+    let loc = symbols[index].location;
+
+    if let Some(next) = symbols.get(index + 1) {
+        Location {
+            line_start: loc.line_start,
+            line_end: next.location.line_start,
+            column_start: loc.column_start,
+            column_end: next.location.column_start.saturating_sub(1),
+        }
+    } else if let Some(prev) = index.checked_sub(1).map(|i| &symbols[i]) {
+        Location {
+            line_start: prev.location.line_end,
+            line_end: loc.line_end,
+            column_start: prev.location.column_end + 1,
+            column_end: loc.column_end,
+        }
+    } else {
+        loc
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -393,6 +528,7 @@ mod tests {
 
         let foo_mod = modules[0].module_id;
 
+        assert_eq!(state.diagnostics.iter().count(), 0);
         assert!(foo_mod.symbol_exists(&state.db, &symbol));
         assert!(!foo_mod.symbol_exists(&state.db, "bar"));
         assert_eq!(
@@ -401,6 +537,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_import_self_with_redundant_alias() {
+        let mut state = State::new(Config::new());
+        let mut modules = vec![hir_module(
+            &mut state,
+            ModuleName::new("foo"),
+            vec![hir::TopLevelExpression::Import(Box::new(hir::Import {
+                source: vec![hir::Identifier {
+                    name: "bar".to_string(),
+                    location: cols(1, 1),
+                }],
+                symbols: vec![hir::ImportSymbol {
+                    name: hir::Identifier {
+                        name: "self".to_string(),
+                        location: cols(1, 1),
+                    },
+                    import_as: hir::Identifier {
+                        name: "bar".to_string(),
+                        location: cols(2, 2),
+                    },
+                    location: cols(1, 1),
+                }],
+                location: cols(1, 1),
+            }))],
+        )];
+
+        let bar_mod = Module::alloc(
+            &mut state.db,
+            ModuleName::new("bar"),
+            "bar.inko".into(),
+        );
+
+        assert!(DefineImportedTypes::run_all(&mut state, &mut modules));
+
+        let foo_mod = modules[0].module_id;
+        let warning = state.diagnostics.iter().next().unwrap();
+
+        assert_eq!(state.diagnostics.iter().count(), 1);
+        assert!(!warning.is_error());
+        assert_eq!(warning.id(), DiagnosticId::RedundantImport);
+        assert_eq!(warning.location(), &cols(2, 2));
+        assert_eq!(
+            foo_mod.use_symbol(&mut state.db, "bar"),
+            Some(Symbol::Module(bar_mod))
+        );
+    }
+
+    #[test]
+    fn test_import_self_with_multiple_aliases() {
+        let mut state = State::new(Config::new());
+        let mut modules = vec![hir_module(
+            &mut state,
+            ModuleName::new("foo"),
+            vec![hir::TopLevelExpression::Import(Box::new(hir::Import {
+                source: vec![hir::Identifier {
+                    name: "bar".to_string(),
+                    location: cols(1, 1),
+                }],
+                symbols: vec![
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: "self".to_string(),
+                            location: cols(1, 1),
+                        },
+                        import_as: hir::Identifier {
+                            name: "a".to_string(),
+                            location: cols(1, 1),
+                        },
+                        location: cols(1, 1),
+                    },
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: "self".to_string(),
+                            location: cols(1, 1),
+                        },
+                        import_as: hir::Identifier {
+                            name: "b".to_string(),
+                            location: cols(1, 1),
+                        },
+                        location: cols(1, 1),
+                    },
+                ],
+                location: cols(1, 1),
+            }))],
+        )];
+
+        let bar_mod = Module::alloc(
+            &mut state.db,
+            ModuleName::new("bar"),
+            "bar.inko".into(),
+        );
+
+        assert!(DefineImportedTypes::run_all(&mut state, &mut modules));
+
+        let foo_mod = modules[0].module_id;
+
+        assert_eq!(state.diagnostics.iter().count(), 0);
+        assert!(!foo_mod.symbol_exists(&state.db, "bar"));
+        assert_eq!(
+            foo_mod.use_symbol(&mut state.db, "a"),
+            Some(Symbol::Module(bar_mod))
+        );
+        assert_eq!(
+            foo_mod.use_symbol(&mut state.db, "b"),
+            Some(Symbol::Module(bar_mod))
+        );
+    }
+
     #[test]
     fn test_import_duplicate_self() {
         let mut state = State::new(Config::new());
@@ -715,6 +959,119 @@ mod tests {
         assert_eq!(error.location(), &cols(4, 4));
     }
 
+    #[test]
+    fn test_import_undefined_symbol_with_suggestion() {
+        let mut state = State::new(Config::new());
+        let mut modules = vec![hir_module(
+            &mut state,
+            ModuleName::new("foo"),
+            vec![hir::TopLevelExpression::Import(Box::new(hir::Import {
+                source: vec![hir::Identifier {
+                    name: "bar".to_string(),
+                    location: cols(1, 1),
+                }],
+                symbols: vec![hir::ImportSymbol {
+                    name: hir::Identifier {
+                        name: "slep".to_string(),
+                        location: cols(4, 4),
+                    },
+                    import_as: hir::Identifier {
+                        name: "slep".to_string(),
+                        location: cols(3, 3),
+                    },
+                    location: cols(2, 2),
+                }],
+                location: cols(1, 2),
+            }))],
+        )];
+
+        let bar_mod = Module::alloc(
+            &mut state.db,
+            ModuleName::new("bar"),
+            "bar.inko".into(),
+        );
+        let method = Method::alloc(
+            &mut state.db,
+            bar_mod,
+            Location::default(),
+            "sleep".to_string(),
+            Visibility::Public,
+            MethodKind::Instance,
+        );
+
+        bar_mod.new_symbol(
+            &mut state.db,
+            "sleep".to_string(),
+            Symbol::Method(method),
+        );
+
+        assert!(!DefineImportedTypes::run_all(&mut state, &mut modules));
+
+        let error = state.diagnostics.iter().next().unwrap();
+
+        assert_eq!(error.id(), DiagnosticId::InvalidSymbol);
+        assert_eq!(
+            error.message(),
+            "the symbol 'slep' is undefined, did you mean 'sleep'?"
+        );
+        assert_eq!(error.location(), &cols(4, 4));
+    }
+
+    #[test]
+    fn test_import_undefined_symbol_without_suggestion() {
+        let mut state = State::new(Config::new());
+        let mut modules = vec![hir_module(
+            &mut state,
+            ModuleName::new("foo"),
+            vec![hir::TopLevelExpression::Import(Box::new(hir::Import {
+                source: vec![hir::Identifier {
+                    name: "bar".to_string(),
+                    location: cols(1, 1),
+                }],
+                symbols: vec![hir::ImportSymbol {
+                    name: hir::Identifier {
+                        name: "Foo".to_string(),
+                        location: cols(4, 4),
+                    },
+                    import_as: hir::Identifier {
+                        name: "Foo".to_string(),
+                        location: cols(3, 3),
+                    },
+                    location: cols(2, 2),
+                }],
+                location: cols(1, 2),
+            }))],
+        )];
+
+        let bar_mod = Module::alloc(
+            &mut state.db,
+            ModuleName::new("bar"),
+            "bar.inko".into(),
+        );
+        let method = Method::alloc(
+            &mut state.db,
+            bar_mod,
+            Location::default(),
+            "sleep".to_string(),
+            Visibility::Public,
+            MethodKind::Instance,
+        );
+
+        bar_mod.new_symbol(
+            &mut state.db,
+            "sleep".to_string(),
+            Symbol::Method(method),
+        );
+
+        assert!(!DefineImportedTypes::run_all(&mut state, &mut modules));
+
+        let error = state.diagnostics.iter().next().unwrap();
+
+        assert_eq!(error.id(), DiagnosticId::InvalidSymbol);
+        assert_eq!(error.message(), "the symbol 'Foo' is undefined");
+        assert_eq!(error.location(), &cols(4, 4));
+    }
+
     #[test]
     fn test_import_private_symbol() {
         let symbol = "_foo".to_string();
@@ -844,4 +1201,466 @@ mod tests {
         assert_eq!(error.file(), &PathBuf::from("test.inko"));
         assert_eq!(error.location(), &cols(4, 4));
     }
+
+    #[test]
+    fn test_import_all() {
+        let mut state = State::new(Config::new());
+        let mut modules = vec![hir_module(
+            &mut state,
+            ModuleName::new("foo"),
+            vec![hir::TopLevelExpression::Import(Box::new(hir::Import {
+                source: vec![hir::Identifier {
+                    name: "bar".to_string(),
+                    location: cols(1, 1),
+                }],
+                symbols: vec![hir::ImportSymbol {
+                    name: hir::Identifier {
+                        name: "*".to_string(),
+                        location: cols(1, 1),
+                    },
+                    import_as: hir::Identifier {
+                        name: "*".to_string(),
+                        location: cols(1, 1),
+                    },
+                    location: cols(1, 1),
+                }],
+                location: cols(1, 1),
+            }))],
+        )];
+
+        let bar_mod = Module::alloc(
+            &mut state.db,
+            ModuleName::new("bar"),
+            "bar.inko".into(),
+        );
+        let fizz_mod = Module::alloc(
+            &mut state.db,
+            ModuleName::new("fizz"),
+            "fizz.inko".into(),
+        );
+        let public = Method::alloc(
+            &mut state.db,
+            bar_mod,
+            Location::default(),
+            "public".to_string(),
+            Visibility::Public,
+            MethodKind::Instance,
+        );
+        let private = Method::alloc(
+            &mut state.db,
+            bar_mod,
+            Location::default(),
+            "private".to_string(),
+            Visibility::Private,
+            MethodKind::Instance,
+        );
+
+        bar_mod.new_symbol(
+            &mut state.db,
+            "public".to_string(),
+            Symbol::Method(public),
+        );
+        bar_mod.new_symbol(
+            &mut state.db,
+            "private".to_string(),
+            Symbol::Method(private),
+        );
+        bar_mod.new_symbol(
+            &mut state.db,
+            "fizz".to_string(),
+            Symbol::Module(fizz_mod),
+        );
+
+        assert!(DefineImportedTypes::run_all(&mut state, &mut modules));
+
+        let foo_mod = modules[0].module_id;
+
+        assert_eq!(
+            foo_mod.use_symbol(&mut state.db, "public"),
+            Some(Symbol::Method(public))
+        );
+        assert!(!foo_mod.symbol_exists(&state.db, "private"));
+        assert!(!foo_mod.symbol_exists(&state.db, "fizz"));
+        assert!(!foo_mod.symbol_exists(&state.db, "*"));
+        assert!(!bar_mod.symbol_is_used(&state.db, "public"));
+    }
+
+    #[test]
+    fn test_import_all_with_duplicate_symbol() {
+        let symbol = "fizz".to_string();
+        let mut state = State::new(Config::new());
+        let mut modules = vec![hir_module(
+            &mut state,
+            ModuleName::new("foo"),
+            vec![hir::TopLevelExpression::Import(Box::new(hir::Import {
+                source: vec![hir::Identifier {
+                    name: "bar".to_string(),
+                    location: cols(1, 1),
+                }],
+                symbols: vec![
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: symbol.clone(),
+                            location: cols(1, 1),
+                        },
+                        import_as: hir::Identifier {
+                            name: symbol.clone(),
+                            location: cols(1, 1),
+                        },
+                        location: cols(1, 1),
+                    },
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: "*".to_string(),
+                            location: cols(2, 2),
+                        },
+                        import_as: hir::Identifier {
+                            name: "*".to_string(),
+                            location: cols(2, 2),
+                        },
+                        location: cols(2, 2),
+                    },
+                ],
+                location: cols(1, 1),
+            }))],
+        )];
+
+        let bar_mod = Module::alloc(
+            &mut state.db,
+            ModuleName::new("bar"),
+            "bar.inko".into(),
+        );
+        let fizz = Method::alloc(
+            &mut state.db,
+            bar_mod,
+            Location::default(),
+            symbol.clone(),
+            Visibility::Public,
+            MethodKind::Instance,
+        );
+
+        bar_mod.new_symbol(&mut state.db, symbol, Symbol::Method(fizz));
+
+        assert!(!DefineImportedTypes::run_all(&mut state, &mut modules));
+
+        let error = state.diagnostics.iter().next().unwrap();
+
+        assert_eq!(error.id(), DiagnosticId::DuplicateSymbol);
+        assert_eq!(error.file(), &PathBuf::from("test.inko"));
+        assert_eq!(error.location(), &cols(2, 2));
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("foo", "foo"), 0);
+        assert_eq!(edit_distance("foo", ""), 3);
+        assert_eq!(edit_distance("", "foo"), 3);
+        assert_eq!(edit_distance("slep", "sleep"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("åb", "ab"), 1);
+    }
+
+    #[test]
+    fn test_check_unused_imports_with_all_symbols_unused() {
+        let mut state = State::new(Config::new());
+        let mut modules = vec![hir_module(
+            &mut state,
+            ModuleName::new("foo"),
+            vec![hir::TopLevelExpression::Import(Box::new(hir::Import {
+                source: vec![hir::Identifier {
+                    name: "bar".to_string(),
+                    location: cols(8, 10),
+                }],
+                symbols: vec![
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: "a".to_string(),
+                            location: cols(13, 13),
+                        },
+                        import_as: hir::Identifier {
+                            name: "a".to_string(),
+                            location: cols(13, 13),
+                        },
+                        location: cols(13, 13),
+                    },
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: "b".to_string(),
+                            location: cols(16, 16),
+                        },
+                        import_as: hir::Identifier {
+                            name: "b".to_string(),
+                            location: cols(16, 16),
+                        },
+                        location: cols(16, 16),
+                    },
+                ],
+                location: cols(1, 17),
+            }))],
+        )];
+
+        let bar_mod = Module::alloc(
+            &mut state.db,
+            ModuleName::new("bar"),
+            "bar.inko".into(),
+        );
+
+        for name in ["a", "b"] {
+            let method = Method::alloc(
+                &mut state.db,
+                bar_mod,
+                Location::default(),
+                name.to_string(),
+                Visibility::Public,
+                MethodKind::Instance,
+            );
+
+            bar_mod.new_symbol(
+                &mut state.db,
+                name.to_string(),
+                Symbol::Method(method),
+            );
+        }
+
+        assert!(DefineImportedTypes::run_all(&mut state, &mut modules));
+        assert!(check_unused_imports(&mut state, &modules));
+
+        let diags = state.diagnostics.iter().collect::<Vec<_>>();
+
+        assert_eq!(diags.len(), 2);
+        assert_eq!(diags[0].id(), DiagnosticId::UnusedSymbol);
+        assert_eq!(diags[0].location(), &cols(13, 13));
+        assert_eq!(diags[0].removable(), Some(&cols(1, 17)));
+        assert_eq!(diags[1].location(), &cols(16, 16));
+        assert_eq!(diags[1].removable(), Some(&cols(1, 17)));
+    }
+
+    #[test]
+    fn test_check_unused_imports_with_symbols_used_in_the_source_module() {
+        let mut state = State::new(Config::new());
+        let mut modules = vec![hir_module(
+            &mut state,
+            ModuleName::new("foo"),
+            vec![hir::TopLevelExpression::Import(Box::new(hir::Import {
+                source: vec![hir::Identifier {
+                    name: "bar".to_string(),
+                    location: cols(8, 10),
+                }],
+                symbols: vec![
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: "a".to_string(),
+                            location: cols(13, 13),
+                        },
+                        import_as: hir::Identifier {
+                            name: "a".to_string(),
+                            location: cols(13, 13),
+                        },
+                        location: cols(13, 13),
+                    },
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: "b".to_string(),
+                            location: cols(16, 16),
+                        },
+                        import_as: hir::Identifier {
+                            name: "b".to_string(),
+                            location: cols(16, 16),
+                        },
+                        location: cols(16, 16),
+                    },
+                ],
+                location: cols(1, 17),
+            }))],
+        )];
+
+        let bar_mod = Module::alloc(
+            &mut state.db,
+            ModuleName::new("bar"),
+            "bar.inko".into(),
+        );
+
+        for name in ["a", "b"] {
+            let method = Method::alloc(
+                &mut state.db,
+                bar_mod,
+                Location::default(),
+                name.to_string(),
+                Visibility::Public,
+                MethodKind::Instance,
+            );
+
+            bar_mod.new_symbol(
+                &mut state.db,
+                name.to_string(),
+                Symbol::Method(method),
+            );
+        }
+
+        assert!(DefineImportedTypes::run_all(&mut state, &mut modules));
+
+        // Uses of the symbols in the module they're defined in shouldn't mark
+        // the imported symbols as used.
+        bar_mod.use_symbol(&mut state.db, "a");
+        bar_mod.use_symbol(&mut state.db, "b");
+        assert!(check_unused_imports(&mut state, &modules));
+
+        let diags = state.diagnostics.iter().collect::<Vec<_>>();
+
+        assert_eq!(diags.len(), 2);
+        assert_eq!(diags[0].id(), DiagnosticId::UnusedSymbol);
+        assert_eq!(diags[0].location(), &cols(13, 13));
+        assert_eq!(diags[0].removable(), Some(&cols(1, 17)));
+        assert_eq!(diags[1].location(), &cols(16, 16));
+        assert_eq!(diags[1].removable(), Some(&cols(1, 17)));
+    }
+
+    #[test]
+    fn test_check_unused_imports_with_some_symbols_unused() {
+        let mut state = State::new(Config::new());
+        let mut modules = vec![hir_module(
+            &mut state,
+            ModuleName::new("foo"),
+            vec![hir::TopLevelExpression::Import(Box::new(hir::Import {
+                source: vec![hir::Identifier {
+                    name: "bar".to_string(),
+                    location: cols(8, 10),
+                }],
+                symbols: vec![
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: "a".to_string(),
+                            location: cols(13, 13),
+                        },
+                        import_as: hir::Identifier {
+                            name: "a".to_string(),
+                            location: cols(13, 13),
+                        },
+                        location: cols(13, 13),
+                    },
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: "b".to_string(),
+                            location: cols(16, 16),
+                        },
+                        import_as: hir::Identifier {
+                            name: "b".to_string(),
+                            location: cols(16, 16),
+                        },
+                        location: cols(16, 16),
+                    },
+                ],
+                location: cols(1, 17),
+            }))],
+        )];
+
+        let bar_mod = Module::alloc(
+            &mut state.db,
+            ModuleName::new("bar"),
+            "bar.inko".into(),
+        );
+
+        for name in ["a", "b"] {
+            let method = Method::alloc(
+                &mut state.db,
+                bar_mod,
+                Location::default(),
+                name.to_string(),
+                Visibility::Public,
+                MethodKind::Instance,
+            );
+
+            bar_mod.new_symbol(
+                &mut state.db,
+                name.to_string(),
+                Symbol::Method(method),
+            );
+        }
+
+        assert!(DefineImportedTypes::run_all(&mut state, &mut modules));
+        modules[0].module_id.use_symbol(&mut state.db, "a");
+        assert!(check_unused_imports(&mut state, &modules));
+
+        let diags = state.diagnostics.iter().collect::<Vec<_>>();
+
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message(), "the symbol 'b' is unused");
+        assert_eq!(diags[0].location(), &cols(16, 16));
+        assert_eq!(diags[0].removable(), Some(&cols(14, 16)));
+    }
+
+    #[test]
+    fn test_check_unused_imports_with_the_first_symbol_unused() {
+        let mut state = State::new(Config::new());
+        let mut modules = vec![hir_module(
+            &mut state,
+            ModuleName::new("foo"),
+            vec![hir::TopLevelExpression::Import(Box::new(hir::Import {
+                source: vec![hir::Identifier {
+                    name: "bar".to_string(),
+                    location: cols(8, 10),
+                }],
+                symbols: vec![
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: "a".to_string(),
+                            location: cols(13, 13),
+                        },
+                        import_as: hir::Identifier {
+                            name: "a".to_string(),
+                            location: cols(13, 13),
+                        },
+                        location: cols(13, 13),
+                    },
+                    hir::ImportSymbol {
+                        name: hir::Identifier {
+                            name: "b".to_string(),
+                            location: cols(16, 16),
+                        },
+                        import_as: hir::Identifier {
+                            name: "b".to_string(),
+                            location: cols(16, 16),
+                        },
+                        location: cols(16, 16),
+                    },
+                ],
+                location: cols(1, 17),
+            }))],
+        )];
+
+        let bar_mod = Module::alloc(
+            &mut state.db,
+            ModuleName::new("bar"),
+            "bar.inko".into(),
+        );
+
+        for name in ["a", "b"] {
+            let method = Method::alloc(
+                &mut state.db,
+                bar_mod,
+                Location::default(),
+                name.to_string(),
+                Visibility::Public,
+                MethodKind::Instance,
+            );
+
+            bar_mod.new_symbol(
+                &mut state.db,
+                name.to_string(),
+                Symbol::Method(method),
+            );
+        }
+
+        assert!(DefineImportedTypes::run_all(&mut state, &mut modules));
+        modules[0].module_id.use_symbol(&mut state.db, "b");
+        assert!(check_unused_imports(&mut state, &modules));
+
+        let diags = state.diagnostics.iter().collect::<Vec<_>>();
+
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message(), "the symbol 'a' is unused");
+        assert_eq!(diags[0].location(), &cols(13, 13));
+        assert_eq!(diags[0].removable(), Some(&cols(13, 15)));
+    }
 }
@@ -6,7 +6,7 @@ use std::ops::RangeInclusive;
 ///
 /// This type doesn't use Rust's range types in order to keep its size down to a
 /// minimum.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Location {
     pub line_start: u32,
     pub line_end: u32,
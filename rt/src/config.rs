@@ -2,9 +2,12 @@ use std::env::var;
 use std::thread::available_parallelism;
 
 /// Sets a configuration field based on an environment variable.
+///
+/// The `$var` argument is the function used to look up the value of the
+/// variable.
 macro_rules! set_from_env {
-    ($config:expr, $field:ident, $key:expr, $value_type:ty) => {{
-        if let Ok(raw_value) = var(concat!("INKO_", $key)) {
+    ($config:expr, $var:ident, $field:ident, $key:expr, $value_type:ty) => {{
+        if let Some(raw_value) = $var(concat!("INKO_", $key)) {
             if let Ok(value) = raw_value.parse::<$value_type>() {
                 if value > 0 {
                     $config.$field = value;
@@ -50,6 +53,14 @@ pub struct Config {
     /// and use the value -1 to signal a file descriptor isn't registered with
     /// any poller.
     pub netpoll_threads: u8,
+
+    /// If set to 1, sockets are registered with the network poller that has the
+    /// fewest sockets registered, instead of the poller assigned to the thread
+    /// the process is running on.
+    ///
+    /// This setting is a boolean, but it's stored as an u8 so it can be parsed
+    /// the same way as the other settings.
+    pub netpoll_balance: u8,
 }
 
 impl Config {
@@ -61,17 +72,25 @@ impl Config {
             process_threads: cpu_count,
             backup_threads: cpu_count * 4,
             netpoll_threads: DEFAULT_NETPOLL_THREADS,
+            netpoll_balance: 0,
             stack_size: DEFAULT_STACK_SIZE,
         }
     }
 
     pub(crate) fn from_env() -> Config {
+        Config::from_vars(|key| var(key).ok())
+    }
+
+    /// Returns a configuration with its settings read from variables, using
+    /// the given function to look up the value of a variable.
+    fn from_vars<F: Fn(&str) -> Option<String>>(var: F) -> Config {
         let mut config = Config::new();
 
-        set_from_env!(config, process_threads, "PROCESS_THREADS", u16);
-        set_from_env!(config, backup_threads, "BACKUP_THREADS", u16);
-        set_from_env!(config, netpoll_threads, "NETPOLL_THREADS", u8);
-        set_from_env!(config, stack_size, "STACK_SIZE", u32);
+        set_from_env!(config, var, process_threads, "PROCESS_THREADS", u16);
+        set_from_env!(config, var, backup_threads, "BACKUP_THREADS", u16);
+        set_from_env!(config, var, netpoll_threads, "NETPOLL_THREADS", u8);
+        set_from_env!(config, var, netpoll_balance, "NETPOLL_BALANCE", u8);
+        set_from_env!(config, var, stack_size, "STACK_SIZE", u32);
 
         config.verify();
         config
@@ -88,13 +107,16 @@ impl Config {
 mod tests {
     use super::*;
 
-    fn var(key: &str) -> Result<&str, ()> {
-        match key {
-            "INKO_FOO" => Ok("1"),
-            "INKO_BAR" => Ok("0"),
-            "INKO_NETPOLL_THREADS" => Ok("4"),
-            _ => Err(()),
-        }
+    fn var(key: &str) -> Option<String> {
+        let val = match key {
+            "INKO_FOO" => "1",
+            "INKO_BAR" => "0",
+            "INKO_NETPOLL_THREADS" => "4",
+            "INKO_BACKUP_THREADS" => "8",
+            _ => return None,
+        };
+
+        Some(val.to_string())
     }
 
     #[test]
@@ -108,11 +130,38 @@ mod tests {
     fn test_set_from_env() {
         let mut cfg = Config::new();
 
-        set_from_env!(cfg, process_threads, "FOO", u16);
+        set_from_env!(cfg, var, process_threads, "FOO", u16);
 
         assert_eq!(cfg.process_threads, 1);
     }
 
+    #[test]
+    fn test_from_vars_with_zero() {
+        let cfg = Config::from_vars(|_| Some("0".to_string()));
+
+        assert_eq!(cfg.stack_size, DEFAULT_STACK_SIZE);
+        assert_eq!(cfg.netpoll_threads, DEFAULT_NETPOLL_THREADS);
+        assert_eq!(cfg.backup_threads, Config::new().backup_threads);
+    }
+
+    #[test]
+    fn test_from_vars_with_backup_threads() {
+        let cfg = Config::from_vars(var);
+
+        assert_eq!(cfg.backup_threads, 8);
+    }
+
+    #[test]
+    fn test_from_vars_with_stack_size() {
+        let cfg = Config::from_vars(|key| match key {
+            "INKO_STACK_SIZE" => Some("1048576".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(cfg.stack_size, 1024 * 1024);
+        assert_eq!(Config::from_vars(|_| None).stack_size, DEFAULT_STACK_SIZE);
+    }
+
     #[test]
     fn test_verify() {
         let mut cfg = Config::new();
@@ -77,6 +77,24 @@ impl MemoryMap {
         MemoryMap { ptr: start, len: size }
     }
 
+    /// Allocates a new memory mapping suitable for use as stack memory, and
+    /// turns the page at offset `guard` into a guard page.
+    ///
+    /// The `guard` offset is relative to the start of the mapping, allowing
+    /// callers to keep any pages before it (e.g. a page of private data)
+    /// readable and writable. The `len` of the returned mapping includes the
+    /// guard page.
+    pub(crate) fn stack_with_guard(
+        size: usize,
+        guard: usize,
+        page_size: usize,
+    ) -> IoResult<MemoryMap> {
+        let mut map = MemoryMap::stack(size);
+
+        map.protect(guard, page_size)?;
+        Ok(map)
+    }
+
     pub(crate) fn protect(
         &mut self,
         start: usize,
@@ -121,4 +139,21 @@ mod tests {
 
         assert!(map.protect(0, page_size()).is_ok());
     }
+
+    #[test]
+    fn test_stack_with_guard() {
+        let page = page_size();
+        let map = MemoryMap::stack_with_guard(page * 4, page, page).unwrap();
+
+        assert_eq!(map.len, page * 4);
+        assert_eq!(map.ptr as usize % (page * 4), 0);
+
+        // The pages before and after the guard page must remain writable.
+        unsafe {
+            std::ptr::write_volatile(map.ptr, 1);
+            std::ptr::write_volatile(map.ptr.add(page * 2), 2);
+            assert_eq!(std::ptr::read_volatile(map.ptr), 1);
+            assert_eq!(std::ptr::read_volatile(map.ptr.add(page * 2)), 2);
+        }
+    }
 }
@@ -6,16 +6,24 @@ use crate::state::State;
 use std::alloc::dealloc;
 use std::cell::UnsafeCell;
 use std::collections::VecDeque;
+use std::hint::spin_loop;
 use std::mem::ManuallyDrop;
 use std::ops::Drop;
 use std::ops::{Deref, DerefMut};
 use std::ptr::{drop_in_place, null_mut, write, NonNull};
 use std::sync::atomic::Ordering;
 use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
 
 const METHOD_IDENTIFIER: &str = "_IM_";
 const CLOSURE_IDENTIFIER: &str = "_IMC_";
 
+/// The maximum amount of time a process is allowed to spin for.
+///
+/// Spinning blocks the OS thread the process runs on, so we limit the time to
+/// prevent processes from blocking a thread for too long.
+pub(crate) const MAX_SPIN_TIME: Duration = Duration::from_millis(1);
+
 /// The type signature for Inko's async methods defined in the native code.
 ///
 /// Native async methods only take a single argument: a `context::Context` that
@@ -223,6 +231,9 @@ pub(crate) struct ProcessState {
     /// If missing and the process is suspended, it means the process is
     /// suspended indefinitely.
     timeout: Option<TimeoutId>,
+
+    /// The name of the process, used to identify it when it panics.
+    name: Option<Box<str>>,
 }
 
 impl ProcessState {
@@ -231,6 +242,7 @@ impl ProcessState {
             mailbox: Mailbox::new(),
             status: ProcessStatus::new(),
             timeout: None,
+            name: None,
         }
     }
 
@@ -465,6 +477,18 @@ impl Process {
         self.state.lock().unwrap().status.is_main()
     }
 
+    /// Sets the name of this process.
+    ///
+    /// An empty name removes the name of the process.
+    pub(crate) fn set_name(&self, name: &str) {
+        self.state.lock().unwrap().name =
+            if name.is_empty() { None } else { Some(name.into()) };
+    }
+
+    pub(crate) fn name(&self) -> Option<String> {
+        self.state.lock().unwrap().name.as_deref().map(|v| v.to_string())
+    }
+
     pub(crate) fn send_message(
         &mut self,
         message: Message,
@@ -664,6 +688,19 @@ impl ProcessPointer {
         self.as_ptr() as usize
     }
 
+    /// Returns a description of the process, used to identify it in error
+    /// messages.
+    pub(crate) fn description(self) -> String {
+        let typ = &self.header.instance_of.name;
+        let id = self.identifier();
+
+        if let Some(name) = self.name() {
+            format!("'{}' named '{}' ({:#x})", typ, name, id)
+        } else {
+            format!("'{}' ({:#x})", typ, id)
+        }
+    }
+
     pub(crate) fn start_blocking(mut self) {
         // Safety: threads are stored in processes before running them.
         unsafe { self.thread() }.start_blocking();
@@ -699,6 +736,24 @@ impl DerefMut for ProcessPointer {
     }
 }
 
+/// Spins the current OS thread for the given duration.
+///
+/// If the duration is greater than `MAX_SPIN_TIME`, no spinning takes place and
+/// `false` is returned.
+pub(crate) fn spin(duration: Duration) -> bool {
+    if duration > MAX_SPIN_TIME {
+        return false;
+    }
+
+    let start = Instant::now();
+
+    while start.elapsed() < duration {
+        spin_loop();
+    }
+
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -726,13 +781,13 @@ mod tests {
 
         if cfg!(any(target_os = "linux", target_os = "freebsd")) {
             assert_eq!(size_of::<UnsafeCell<Mutex<()>>>(), 8);
-            assert_eq!(size_of::<Process>(), 104);
+            assert_eq!(size_of::<Process>(), 120);
         } else {
             assert_eq!(size_of::<UnsafeCell<Mutex<()>>>(), 16);
-            assert_eq!(size_of::<Process>(), 120);
+            assert_eq!(size_of::<Process>(), 136);
         }
 
-        assert_eq!(size_of::<ProcessState>(), 48);
+        assert_eq!(size_of::<ProcessState>(), 64);
         assert_eq!(size_of::<Option<NonNull<Thread>>>(), 8);
     }
 
@@ -746,13 +801,32 @@ mod tests {
         assert_eq!(
             offset_of!(proc, fields),
             if cfg!(any(target_os = "linux", target_os = "freebsd")) {
-                104
-            } else {
                 120
+            } else {
+                136
             }
         );
     }
 
+    #[test]
+    fn test_spin() {
+        let start = Instant::now();
+
+        assert!(spin(Duration::from_micros(100)));
+        assert!(start.elapsed() >= Duration::from_micros(100));
+        assert!(spin(Duration::ZERO));
+        assert!(spin(MAX_SPIN_TIME));
+    }
+
+    #[test]
+    fn test_spin_with_a_duration_that_is_too_large() {
+        let start = Instant::now();
+
+        assert!(!spin(MAX_SPIN_TIME + Duration::from_nanos(1)));
+        assert!(!spin(Duration::from_secs(10)));
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
     #[test]
     fn test_process_status_new() {
         let status = ProcessStatus::new();
@@ -973,6 +1047,37 @@ mod tests {
         assert!(process.is_main());
     }
 
+    #[test]
+    fn test_process_set_name() {
+        let typ = empty_process_type("A");
+        let stack = Stack::new(32, page_size());
+        let process = OwnedProcess::new(Process::alloc(*typ, stack));
+
+        assert_eq!(process.name(), None);
+
+        process.set_name("worker");
+        assert_eq!(process.name(), Some("worker".to_string()));
+
+        process.set_name("");
+        assert_eq!(process.name(), None);
+    }
+
+    #[test]
+    fn test_process_pointer_description() {
+        let typ = empty_process_type("A");
+        let stack = Stack::new(32, page_size());
+        let process = OwnedProcess::new(Process::alloc(*typ, stack));
+        let id = process.identifier();
+
+        assert_eq!(process.description(), format!("'A' ({:#x})", id));
+
+        process.set_name("worker");
+        assert_eq!(
+            process.description(),
+            format!("'A' named 'worker' ({:#x})", id)
+        );
+    }
+
     #[test]
     fn test_process_state_suspend() {
         let typ = empty_process_type("A");
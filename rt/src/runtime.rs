@@ -2,7 +2,7 @@ mod env;
 mod float;
 mod general;
 mod int;
-mod process;
+pub(crate) mod process;
 mod signal;
 mod socket;
 mod string;
@@ -17,7 +17,7 @@ use crate::process::{NativeAsyncMethod, Process};
 use crate::scheduler::reset_affinity;
 use crate::scheduler::signal as signal_sched;
 use crate::stack::total_stack_size;
-use crate::stack::Stack;
+use crate::stack::{install_overflow_handler, Stack};
 use crate::state::{RcState, State};
 use rustix::param::page_size;
 use std::ffi::CStr;
@@ -161,6 +161,9 @@ impl Runtime {
         }
 
         let stack_size = self.state.config.stack_size as usize;
+
+        install_overflow_handler();
+
         let stack = Stack::new(stack_size, page_size());
         let main_proc = Process::main(main_type, main_method, stack);
 
@@ -1,14 +1,17 @@
 use crate::context;
 use crate::mem::{PrimitiveString, TypePointer};
 use crate::process::{
-    Message, NativeAsyncMethod, Process, ProcessPointer, RescheduleRights,
-    StackFrame,
+    spin, Message, NativeAsyncMethod, Process, ProcessPointer,
+    RescheduleRights, StackFrame,
 };
+use crate::result::error_to_int;
 use crate::scheduler::process::Action;
+use crate::scheduler::set_thread_affinity;
 use crate::scheduler::timeouts::Deadline;
 use crate::state::State;
 use std::fmt::Write as _;
 use std::process::exit;
+use std::slice;
 use std::str;
 use std::sync::atomic::{AtomicU8, Ordering};
 use std::time::Duration;
@@ -44,9 +47,8 @@ pub(crate) fn panic(process: ProcessPointer, message: &str) -> ! {
 
     let _ = write!(
         buffer,
-        "\nProcess '{}' ({:#x}) panicked: {}",
-        process.header.instance_of.name,
-        process.identifier(),
+        "\nProcess {} panicked: {}",
+        process.description(),
         message
     );
 
@@ -62,6 +64,24 @@ pub unsafe extern "system" fn inko_process_panic(
     panic(process, message.as_str());
 }
 
+#[no_mangle]
+pub unsafe extern "system" fn inko_process_set_name(
+    process: ProcessPointer,
+    name: PrimitiveString,
+) {
+    process.set_name(name.as_str());
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn inko_process_name(
+    process: ProcessPointer,
+) -> PrimitiveString {
+    process
+        .name()
+        .map(PrimitiveString::owned)
+        .unwrap_or_else(PrimitiveString::empty)
+}
+
 #[no_mangle]
 pub unsafe extern "system" fn inko_process_new(
     mut process: ProcessPointer,
@@ -212,6 +232,76 @@ pub unsafe extern "system" fn inko_process_stop_blocking(
     process.stop_blocking();
 }
 
+#[no_mangle]
+pub unsafe extern "system" fn inko_process_spin(
+    state: *const State,
+    process: ProcessPointer,
+    nanos: i64,
+) {
+    // Durations that are too long result in the process being suspended
+    // instead, such that we don't block the OS thread for too long.
+    if !spin(Duration::from_nanos(nanos.max(0) as _)) {
+        inko_process_suspend(state, process, nanos);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn inko_process_set_affinity(
+    process: ProcessPointer,
+    cores: *const i64,
+    size: i64,
+) -> i64 {
+    // The pointer of an empty array is NULL, and from_raw_parts() requires a
+    // non-NULL pointer even if the size is zero.
+    if size <= 0 || cores.is_null() {
+        return libc::EINVAL as i64;
+    }
+
+    // Regular processes may be moved between OS threads at any given point, so
+    // setting the affinity of the current OS thread would affect whatever
+    // process runs on it next. The main process is the only process that always
+    // runs on the same OS thread, so we only allow it for that process.
+    if !process.is_main() {
+        return libc::ENOTSUP as i64;
+    }
+
+    let cores: Vec<usize> = slice::from_raw_parts(cores, size as usize)
+        .iter()
+        .map(|&core| core as usize)
+        .collect();
+
+    match set_thread_affinity(&cores) {
+        Ok(_) => 0,
+        Err(err) => error_to_int(err),
+    }
+}
+
+/// Statistics about a process thread, as exposed to the standard library.
+#[repr(C)]
+pub struct SchedulerStatistics {
+    local: i64,
+    stolen: i64,
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn inko_scheduler_threads(
+    state: *const State,
+) -> i64 {
+    (*state).scheduler.threads() as i64
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn inko_scheduler_statistics(
+    state: *const State,
+    index: i64,
+) -> SchedulerStatistics {
+    let stats = (*state).scheduler.statistics(index as usize);
+
+    stats.map_or(SchedulerStatistics { local: 0, stolen: 0 }, |s| {
+        SchedulerStatistics { local: s.local as i64, stolen: s.stolen as i64 }
+    })
+}
+
 #[no_mangle]
 pub unsafe extern "system" fn inko_process_wait_for_value(
     process: ProcessPointer,
@@ -5,6 +5,12 @@ use crate::scheduler::timeouts::Deadline;
 use crate::socket::Socket;
 use crate::state::State;
 
+/// Suspends the current process until the socket is ready for the given
+/// interest, or until the deadline expires.
+///
+/// A deadline of -1 means the process waits indefinitely. The return value is
+/// `true` if the socket became ready, and `false` if the deadline expired first
+/// (in which case the socket is deregistered before returning).
 #[no_mangle]
 pub(crate) unsafe extern "system" fn inko_socket_poll(
     state: *const State,
@@ -34,7 +40,11 @@ pub(crate) unsafe extern "system" fn inko_socket_poll(
             proc_state.waiting_for_io(None);
         }
 
-        socket.register(state, process, poll_id, interest);
+        if state.config.netpoll_balance > 0 {
+            socket.register_balanced(state, process, interest);
+        } else {
+            socket.register(state, process, poll_id, interest);
+        }
     }
 
     // Safety: the current thread is holding on to the process' run lock, so if
@@ -53,3 +63,11 @@ pub(crate) unsafe extern "system" fn inko_socket_poll(
         true
     }
 }
+
+#[no_mangle]
+pub(crate) unsafe extern "system" fn inko_socket_release(
+    state: *const State,
+    socket: *mut Socket,
+) {
+    (*socket).release(&*state);
+}
@@ -54,14 +54,172 @@ pub unsafe extern "system" fn inko_string_to_float(
         str::from_utf8_unchecked(slice::from_raw_parts(bytes, size as _));
 
     let parsed = match slice {
-        "Infinity" => Ok(f64::INFINITY),
-        "-Infinity" => Ok(f64::NEG_INFINITY),
-        _ => slice.parse::<f64>(),
+        "Infinity" => Some(f64::INFINITY),
+        "-Infinity" => Some(f64::NEG_INFINITY),
+        _ => parse_hex_float(slice.as_bytes())
+            .or_else(|| slice.parse::<f64>().ok()),
     };
 
     parsed
         .map(|v| InkoResult::ok(v.to_bits() as _))
-        .unwrap_or_else(|_| InkoResult::none())
+        .unwrap_or_else(InkoResult::none)
+}
+
+/// Parses a hexadecimal float such as `0x1.8p3`.
+///
+/// The input consists of an optional sign, a `0x` or `0X` prefix, one or more
+/// hexadecimal digits with an optional fractional part, and an optional binary
+/// exponent (`p` or `P`) in base 10. If the input isn't a valid hexadecimal
+/// float, `None` is returned.
+fn parse_hex_float(input: &[u8]) -> Option<f64> {
+    let (negative, rest) = split_sign(input);
+    let mut rest = match rest {
+        [b'0', b'x' | b'X', rest @ ..] => rest,
+        _ => return None,
+    };
+    let mut mantissa = 0_u64;
+    let mut exponent = 0_i64;
+    let mut digits = 0;
+    let mut fraction = false;
+    let mut truncated = false;
+
+    while let [byte, tail @ ..] = rest {
+        if *byte == b'.' && !fraction {
+            fraction = true;
+            rest = tail;
+            continue;
+        }
+
+        let Some(digit) = (*byte as char).to_digit(16) else { break };
+
+        // Digits that don't fit in the mantissa are only tracked such that we
+        // can still round the result correctly.
+        if mantissa >> 60 == 0 {
+            mantissa = (mantissa << 4) | digit as u64;
+            exponent -= if fraction { 4 } else { 0 };
+        } else {
+            truncated |= digit != 0;
+            exponent += if fraction { 0 } else { 4 };
+        }
+
+        digits += 1;
+        rest = tail;
+    }
+
+    if digits == 0 {
+        return None;
+    }
+
+    if let [b'p' | b'P', tail @ ..] = rest {
+        let (negative, tail) = split_sign(tail);
+
+        if tail.is_empty() || !tail.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        let value = tail.iter().fold(0_i64, |acc, &byte| {
+            acc.saturating_mul(10).saturating_add((byte - b'0') as i64)
+        });
+
+        exponent = if negative {
+            exponent.saturating_sub(value)
+        } else {
+            exponent.saturating_add(value)
+        };
+        rest = &[];
+    }
+
+    if !rest.is_empty() {
+        return None;
+    }
+
+    if mantissa == 0 {
+        return Some(if negative { -0.0 } else { 0.0 });
+    }
+
+    // The mantissa has more bits than an f64 can store when digits are
+    // truncated, so setting the lowest bit ensures we round the same way as we
+    // would if the truncated digits were kept.
+    if truncated {
+        mantissa |= 1;
+    }
+
+    // Any exponent outside of this range results in zero or infinity, so we
+    // clamp it to limit the number of steps needed to scale the value.
+    let mut exponent = exponent.clamp(-2200, 2200);
+    let bits = 64 - mantissa.leading_zeros() as i64;
+
+    // Subnormal values have fewer bits of precision than normal values. We
+    // round the mantissa to the precision of the final value here, instead of
+    // rounding it to 53 bits and then again when scaling it to a subnormal
+    // value, as rounding twice may produce the wrong result.
+    let precision = (exponent + bits - 1 + 1075).min(53);
+    let shift = bits - precision;
+
+    if shift > 0 {
+        mantissa = round_shift(mantissa, shift as u32);
+        exponent += shift;
+    }
+
+    // The mantissa now fits in the precision of the result, so the conversion
+    // and scaling below are exact unless the result is too large.
+    let mut value = mantissa as f64;
+
+    while exponent > 1023 {
+        value *= f64::from_bits(2046 << 52);
+        exponent -= 1023;
+    }
+
+    while exponent < -1022 {
+        value *= f64::from_bits(1 << 52);
+        exponent += 1022;
+    }
+
+    value *= f64::from_bits(((exponent + 1023) as u64) << 52);
+    Some(if negative { -value } else { value })
+}
+
+/// Shifts `value` to the right by `shift` bits, rounding the result to the
+/// nearest value and ties to even.
+fn round_shift(value: u64, shift: u32) -> u64 {
+    let value = value as u128;
+    let shift = shift.min(65);
+    let kept = value >> shift;
+    let rest = value & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+
+    if rest > half || (rest == half && kept & 1 == 1) {
+        (kept + 1) as u64
+    } else {
+        kept as u64
+    }
+}
+
+fn split_sign(input: &[u8]) -> (bool, &[u8]) {
+    match input {
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        _ => (false, input),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn inko_string_count_class(
+    string: PrimitiveString,
+    class: i64,
+) -> i64 {
+    let chars = string.as_str().chars();
+    let count = match class {
+        0 => chars.filter(|c| c.is_numeric()).count(),
+        1 => chars.filter(|c| c.is_alphabetic()).count(),
+        2 => chars.filter(|c| c.is_alphanumeric()).count(),
+        3 => chars.filter(|c| c.is_whitespace()).count(),
+        4 => chars.filter(|c| c.is_uppercase()).count(),
+        5 => chars.filter(|c| c.is_lowercase()).count(),
+        _ => 0,
+    };
+
+    count as i64
 }
 
 #[no_mangle]
@@ -100,3 +258,69 @@ pub unsafe extern "system" fn inko_string_from_pointer(
 ) -> PrimitiveString {
     PrimitiveString::owned(CStr::from_ptr(ptr).to_string_lossy().into_owned())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Option<u64> {
+        parse_hex_float(input.as_bytes()).map(f64::to_bits)
+    }
+
+    #[test]
+    fn test_parse_hex_float() {
+        assert_eq!(parse("0x1.8p3"), Some(12.0_f64.to_bits()));
+        assert_eq!(parse("-0x1p-2"), Some((-0.25_f64).to_bits()));
+        assert_eq!(parse("0x0p0"), Some(0));
+        assert_eq!(parse("-0x0p0"), Some((-0.0_f64).to_bits()));
+        assert_eq!(parse("0x1p-1022"), Some(f64::MIN_POSITIVE.to_bits()));
+        assert_eq!(parse("0x1.fffffffffffffp1023"), Some(f64::MAX.to_bits()));
+        assert_eq!(parse("0x1p1024"), Some(f64::INFINITY.to_bits()));
+        assert_eq!(parse("0x1"), Some(1.0_f64.to_bits()));
+        assert_eq!(parse("0x"), None);
+        assert_eq!(parse("0x1p"), None);
+        assert_eq!(parse("1.0"), None);
+    }
+
+    #[test]
+    fn test_parse_hex_float_with_rounding() {
+        // Ties are rounded to the nearest even value.
+        assert_eq!(parse("0x1.00000000000008p0"), Some(1.0_f64.to_bits()));
+        assert_eq!(parse("0x1.00000000000018p0"), Some(1.0_f64.to_bits() + 2));
+
+        // Truncated digits still affect the rounding.
+        assert_eq!(
+            parse("0x1.000000000000080000000001p0"),
+            Some(1.0_f64.to_bits() + 1)
+        );
+    }
+
+    #[test]
+    fn test_parse_hex_float_with_subnormal_values() {
+        assert_eq!(parse("0x1p-1074"), Some(1));
+        assert_eq!(parse("0x1p-1073"), Some(2));
+        assert_eq!(parse("0x1.0000000000000fp-1074"), Some(1));
+        assert_eq!(parse("0x1.7ffffffffffff8p-1074"), Some(1));
+        assert_eq!(parse("0x1.8p-1074"), Some(2));
+        assert_eq!(parse("0x2.8p-1074"), Some(2));
+        assert_eq!(parse("0x0.8p-1074"), Some(0));
+        assert_eq!(parse("0x0.8000000000001p-1074"), Some(1));
+        assert_eq!(parse("0x1p-1076"), Some(0));
+        assert_eq!(parse("0x1p-5000"), Some(0));
+        assert_eq!(parse("0x1.fffffffffffff8p-1023"), Some(1 << 52));
+    }
+
+    #[test]
+    fn test_round_shift() {
+        assert_eq!(round_shift(0b1011, 1), 0b110);
+        assert_eq!(round_shift(0b1001, 1), 0b100);
+        assert_eq!(round_shift(0b1010, 2), 0b10);
+        assert_eq!(round_shift(0b1110, 2), 0b100);
+        assert_eq!(round_shift(0b1011, 2), 0b11);
+        assert_eq!(round_shift(0b1001, 2), 0b10);
+        assert_eq!(round_shift(u64::MAX, 64), 1);
+        assert_eq!(round_shift(1 << 63, 64), 0);
+        assert_eq!(round_shift(u64::MAX, 65), 0);
+        assert_eq!(round_shift(u64::MAX, 100), 0);
+    }
+}
@@ -4,6 +4,7 @@ pub mod timeouts;
 
 #[cfg(target_os = "linux")]
 use rustix::process::{sched_setaffinity, CpuSet, Pid};
+use std::io;
 
 #[cfg(target_os = "linux")]
 pub(crate) fn pin_thread_to_core(core: usize) {
@@ -18,6 +19,30 @@ pub(crate) fn pin_thread_to_core(_core: usize) {
     // Pinning is only implemented for Linux at this time.
 }
 
+/// Binds the current OS thread to the given CPU cores.
+#[cfg(target_os = "linux")]
+pub(crate) fn set_thread_affinity(cores: &[usize]) -> io::Result<()> {
+    let mut set = CpuSet::new();
+
+    for &core in cores {
+        // CpuSet::set() panics for out of bounds values, so we have to check
+        // the bounds ourselves.
+        if core >= CpuSet::MAX_CPU {
+            return Err(io::Error::from_raw_os_error(libc::EINVAL));
+        }
+
+        set.set(core);
+    }
+
+    sched_setaffinity(Pid::from_raw(0), &set).map_err(io::Error::from)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn set_thread_affinity(_cores: &[usize]) -> io::Result<()> {
+    // Pinning is only implemented for Linux at this time.
+    Err(io::Error::from_raw_os_error(libc::ENOTSUP))
+}
+
 #[cfg(target_os = "linux")]
 pub(crate) fn reset_affinity() {
     let mut set = CpuSet::new();
@@ -33,3 +58,30 @@ pub(crate) fn reset_affinity() {
 pub(crate) fn reset_affinity() {
     // Only implemented on Linux.
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_set_thread_affinity() {
+        // We use a separate thread so the affinity of the test thread isn't
+        // affected.
+        thread::spawn(|| {
+            assert!(set_thread_affinity(&[0]).is_ok());
+            assert!(set_thread_affinity(&[CpuSet::MAX_CPU]).is_err());
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[test]
+    fn test_set_thread_affinity() {
+        thread::spawn(|| assert!(set_thread_affinity(&[0]).is_err()))
+            .join()
+            .unwrap();
+    }
+}
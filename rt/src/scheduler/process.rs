@@ -3,7 +3,7 @@ use crate::arc_without_weak::ArcWithoutWeak;
 use crate::context;
 use crate::process::{Process, ProcessPointer, Task};
 use crate::scheduler::pin_thread_to_core;
-use crate::stack::StackPool;
+use crate::stack::{setup_signal_stack, StackPool};
 use crate::state::{RcState, State};
 use crossbeam_queue::ArrayQueue;
 use crossbeam_utils::atomic::AtomicCell;
@@ -143,6 +143,39 @@ struct Shared {
     ///
     /// A value of zero indicates the thread isn't blocking.
     blocked_at: AtomicU64,
+
+    /// The number of processes taken from this thread's own queue.
+    ///
+    /// This counter and the `stolen` counter are only used for gathering
+    /// statistics, and thus only use relaxed atomic operations.
+    local: AtomicU64,
+
+    /// The number of processes stolen from the queues of other threads.
+    stolen: AtomicU64,
+}
+
+impl Shared {
+    fn new(queue: ArcWithoutWeak<ArrayQueue<ProcessPointer>>) -> Shared {
+        Shared {
+            queue,
+            blocked_at: AtomicU64::new(NOT_BLOCKING),
+            local: AtomicU64::new(0),
+            stolen: AtomicU64::new(0),
+        }
+    }
+}
+
+/// Statistics about the processes scheduled by a single process thread.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub(crate) struct ThreadStatistics {
+    /// The number of processes taken from the thread's own queue.
+    ///
+    /// Processes stolen from other threads in bulk are moved into the thread's
+    /// own queue, and thus also count towards this number once they run.
+    pub(crate) local: u64,
+
+    /// The number of processes stolen from the queues of other threads.
+    pub(crate) stolen: u64,
 }
 
 /// The private half of a thread, used only by the OS thread this state belongs
@@ -358,6 +391,8 @@ impl Thread {
     }
 
     fn run(&mut self, state: &State) {
+        setup_signal_stack();
+
         while self.pool.is_alive() {
             if self.backup {
                 let mut blocked = self.pool.blocked_threads.lock().unwrap();
@@ -406,6 +441,8 @@ impl Thread {
     }
 
     fn run_main(&mut self, state: &State) {
+        setup_signal_stack();
+
         while self.pool.is_alive() {
             if let Some(process) = self.pop_main_process() {
                 self.run_process(state, process);
@@ -421,7 +458,10 @@ impl Thread {
     }
 
     fn next_local_process(&self) -> Option<ProcessPointer> {
-        self.work.pop()
+        let process = self.work.pop()?;
+
+        self.pool.threads[self.id].local.fetch_add(1, Ordering::Relaxed);
+        Some(process)
     }
 
     fn steal_from_thread(&mut self) -> Option<ProcessPointer> {
@@ -449,18 +489,27 @@ impl Thread {
             if let Some(initial) = steal_from.queue.pop() {
                 let len = steal_from.queue.len();
                 let steal = min(len / 2, STEAL_LIMIT);
+                let mut stolen = 1;
 
                 for _ in 0..steal {
                     if let Some(process) = steal_from.queue.pop() {
+                        // Processes that don't fit in our queue end up in the
+                        // global queue, so we don't count these as stolen.
                         if let Err(process) = self.work.push(process) {
                             self.pool.schedule(process);
                             break;
                         }
+
+                        stolen += 1;
                     } else {
                         break;
                     }
                 }
 
+                self.pool.threads[self.id]
+                    .stolen
+                    .fetch_add(stolen, Ordering::Relaxed);
+
                 return Some(initial);
             }
         }
@@ -962,7 +1011,7 @@ impl Scheduler {
             let queue =
                 ArcWithoutWeak::new(ArrayQueue::new(LOCAL_QUEUE_CAPACITY));
 
-            shared.push(Shared { queue, blocked_at: AtomicU64::new(0) });
+            shared.push(Shared::new(queue));
         }
 
         let shared = ArcWithoutWeak::new(Pool {
@@ -999,6 +1048,26 @@ impl Scheduler {
         self.pool.terminate();
     }
 
+    /// Returns the number of threads used for running regular processes.
+    ///
+    /// This excludes the main thread, as it only runs the main process.
+    pub(crate) fn threads(&self) -> usize {
+        self.primary - 1
+    }
+
+    /// Returns the statistics of the process thread at the given index.
+    ///
+    /// The index is in the range `0..threads()`. If the index is out of
+    /// bounds, a `None` is returned.
+    pub(crate) fn statistics(&self, index: usize) -> Option<ThreadStatistics> {
+        let thread = self.pool.threads.get(index.checked_add(1)?)?;
+
+        Some(ThreadStatistics {
+            local: thread.local.load(Ordering::Relaxed),
+            stolen: thread.stolen.load(Ordering::Relaxed),
+        })
+    }
+
     pub(crate) fn run(&self, state: &RcState, process: ProcessPointer) {
         let pollers = state.network_pollers.len();
         let cores = state.cores as usize;
@@ -1145,6 +1214,129 @@ mod tests {
         assert_eq!(thread1.work.len(), 0);
     }
 
+    #[test]
+    fn test_thread_statistics_with_local_job() {
+        let typ = empty_process_type("A");
+        let process = new_process_with_message(*typ, method).take_and_forget();
+        let state = setup();
+        let mut thread = Thread::new(1, 0, state.scheduler.pool.clone());
+
+        thread.schedule(process);
+        thread.run(&state);
+
+        assert_eq!(
+            state.scheduler.statistics(0),
+            Some(ThreadStatistics { local: 1, stolen: 0 })
+        );
+        assert_eq!(
+            state.scheduler.statistics(1),
+            Some(ThreadStatistics { local: 0, stolen: 0 })
+        );
+    }
+
+    #[test]
+    fn test_thread_statistics_with_stolen_job() {
+        let typ = empty_process_type("A");
+        let process = new_process_with_message(*typ, method).take_and_forget();
+        let state = setup();
+        let mut thread0 = Thread::new(1, 0, state.scheduler.pool.clone());
+        let mut thread1 = Thread::new(2, 0, state.scheduler.pool.clone());
+
+        thread1.schedule(process);
+        thread0.run(&state);
+
+        assert_eq!(
+            state.scheduler.statistics(0),
+            Some(ThreadStatistics { local: 0, stolen: 1 })
+        );
+        assert_eq!(
+            state.scheduler.statistics(1),
+            Some(ThreadStatistics { local: 0, stolen: 0 })
+        );
+    }
+
+    #[test]
+    fn test_thread_statistics_with_many_processes() {
+        static REMAINING: AtomicU64 = AtomicU64::new(0);
+
+        unsafe extern "system" fn short(data: *mut u8) {
+            let mut proc = ProcessPointer::new(data as _);
+
+            proc.thread().action = Action::Terminate;
+
+            if REMAINING.fetch_sub(1, Ordering::AcqRel) == 1 {
+                proc.thread().pool.terminate();
+            }
+
+            context::switch(proc);
+        }
+
+        let typ = empty_process_type("A");
+        let state = setup();
+        let mut thread0 = Thread::new(1, 0, state.scheduler.pool.clone());
+        let mut thread1 = Thread::new(2, 0, state.scheduler.pool.clone());
+
+        REMAINING.store(64, Ordering::Release);
+
+        for _ in 0..64 {
+            thread1.schedule(
+                new_process_with_message(*typ, short).take_and_forget(),
+            );
+        }
+
+        thread0.run(&state);
+
+        let stats = [0, 1].map(|i| state.scheduler.statistics(i).unwrap());
+
+        assert_eq!(REMAINING.load(Ordering::Acquire), 0);
+        assert_eq!(thread1.work.len(), 0);
+        assert!(stats.iter().any(|s| s.stolen > 0));
+    }
+
+    #[test]
+    fn test_thread_steal_from_thread_with_full_local_queue() {
+        let typ = empty_process_type("A");
+        let process = new_process(*typ).take_and_forget();
+        let scheduler = Scheduler::new(2, 1, 32);
+        let mut thread0 = Thread::new(1, 0, scheduler.pool.clone());
+        let thread1 = Thread::new(2, 0, scheduler.pool.clone());
+
+        for _ in 0..(LOCAL_QUEUE_CAPACITY - 2) {
+            thread0.work.push(process).unwrap();
+        }
+
+        for _ in 0..9 {
+            thread1.work.push(process).unwrap();
+        }
+
+        let stolen = thread0.steal_from_thread().is_some();
+        let global = scheduler.pool.global.lock().unwrap().len();
+
+        // Since we schedule the same process multiple times, we have to ensure
+        // it doesn't also get dropped multiple times.
+        while thread0.work.pop().is_some() || thread1.work.pop().is_some() {}
+        scheduler.pool.global.lock().unwrap().clear();
+
+        // The initial process and the two processes that fit in the local
+        // queue are stolen, while the process that doesn't fit is moved to the
+        // global queue and thus isn't counted.
+        assert!(stolen);
+        assert_eq!(global, 1);
+        assert_eq!(
+            scheduler.statistics(0),
+            Some(ThreadStatistics { local: 0, stolen: 3 })
+        );
+    }
+
+    #[test]
+    fn test_scheduler_threads() {
+        let scheduler = Scheduler::new(2, 1, 32);
+
+        assert_eq!(scheduler.threads(), 2);
+        assert!(scheduler.statistics(1).is_some());
+        assert!(scheduler.statistics(2).is_none());
+    }
+
     #[test]
     fn test_thread_run_with_global_job() {
         let typ = empty_process_type("A");
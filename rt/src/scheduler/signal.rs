@@ -2,7 +2,7 @@ use crate::process::ProcessPointer;
 use crate::state::RcState;
 use libc::{
     kill, pthread_sigmask, sigaddset, sigdelset, sigemptyset, sigfillset,
-    sigset_t, sigwait, SIGPIPE, SIGURG, SIG_SETMASK,
+    sigset_t, sigwait, SIGBUS, SIGPIPE, SIGSEGV, SIGURG, SIG_SETMASK,
 };
 use std::ffi::c_int;
 use std::mem::MaybeUninit;
@@ -77,7 +77,14 @@ impl SignalSet {
 
 /// Ignores the allowed signals sent to the current _process_.
 pub(crate) fn block_all() {
-    SignalSet::full().block()
+    let mut set = SignalSet::full();
+
+    // These signals are produced when a process overflows its stack. If they're
+    // blocked, the system terminates the program without running the stack
+    // overflow handler.
+    set.remove(SIGSEGV);
+    set.remove(SIGBUS);
+    set.block()
 }
 
 /// Notifies the current process that the signals to wait for has changed.
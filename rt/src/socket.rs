@@ -33,6 +33,15 @@ pub struct Socket {
 }
 
 impl Socket {
+    /// Returns the ID of the network poller the socket is registered with, if
+    /// any.
+    pub(crate) fn poller_id(&self) -> Option<usize> {
+        match self.registered.load(Ordering::Acquire) {
+            NOT_REGISTERED => None,
+            id => Some(id as usize),
+        }
+    }
+
     pub(crate) fn register(
         &mut self,
         state: &State,
@@ -40,7 +49,7 @@ impl Socket {
         thread_poller_id: usize,
         interest: Interest,
     ) {
-        let existing_id = self.registered.load(Ordering::Acquire);
+        let existing_id = self.poller_id();
 
         // Safety: the standard library guarantees the file descriptor is valid
         // at this point.
@@ -54,39 +63,270 @@ impl Socket {
         //
         // 1. Set "registered" _first_ (if necessary)
         // 2. Add the socket to the poller
-        if existing_id == NOT_REGISTERED {
+        if let Some(id) = existing_id {
+            let poller = &state.network_pollers[id];
+
+            poller.modify(process, fd, interest);
+        } else {
             let poller = &state.network_pollers[thread_poller_id];
 
+            // The socket counts are only used when balancing sockets across
+            // pollers, so we don't maintain them otherwise.
+            if state.config.netpoll_balance > 0 {
+                state.network_poller_sockets[thread_poller_id]
+                    .fetch_add(1, Ordering::AcqRel);
+            }
+
             self.registered.store(thread_poller_id as i8, Ordering::Release);
             poller.add(process, fd, interest);
-        } else {
-            let poller = &state.network_pollers[existing_id as usize];
-
-            poller.modify(process, fd, interest);
         }
         // *DO NOT* use "self" from here on, as the socket/process may already
         // be running on a different thread.
     }
 
+    /// Registers the socket with the network poller that has the fewest sockets
+    /// registered with it.
+    ///
+    /// If the socket is already registered, the existing registration is
+    /// modified instead, meaning a socket doesn't move between pollers.
+    pub(crate) fn register_balanced(
+        &mut self,
+        state: &State,
+        process: ProcessPointer,
+        interest: Interest,
+    ) {
+        let id = self.poller_id().unwrap_or_else(|| least_loaded_poller(state));
+
+        self.register(state, process, id, interest);
+    }
+
     pub(crate) fn deregister(&mut self, state: &State) {
-        let poller_id = self.registered.load(Ordering::Acquire) as usize;
+        // The socket may already be deregistered, such as when it's closed
+        // after a previous operation timed out. In this case there's nothing to
+        // do, and using the ID as an index would result in a panic.
+        let Some(poller_id) = self.poller_id() else { return };
 
         // Safety: the standard library guarantees the file descriptor is valid
         // at this point.
         let fd = unsafe { BorrowedFd::borrow_raw(self.inner) };
 
         state.network_pollers[poller_id].delete(fd);
+        self.release(state);
+    }
+
+    /// Marks the socket as no longer being registered with its poller, without
+    /// removing it from the poller.
+    ///
+    /// This is used when closing a socket, as closing the file descriptor
+    /// already removes it from the poller.
+    pub(crate) fn release(&mut self, state: &State) {
+        let Some(poller_id) = self.poller_id() else { return };
+
+        if state.config.netpoll_balance > 0 {
+            state.network_poller_sockets[poller_id]
+                .fetch_sub(1, Ordering::AcqRel);
+        }
+
         self.registered.store(NOT_REGISTERED, Ordering::Release);
     }
 }
 
+/// Returns the ID of the network poller with the fewest sockets registered.
+///
+/// If multiple pollers have the same number of sockets, the one with the lowest
+/// ID is used.
+fn least_loaded_poller(state: &State) -> usize {
+    state
+        .network_poller_sockets
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, count)| count.load(Ordering::Acquire))
+        .map(|(id, _)| id)
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::Config;
+    use crate::state::RcState;
+    use crate::test::{empty_process_type, new_process, setup};
     use std::mem::size_of;
+    use std::net::UdpSocket;
+    use std::os::fd::AsRawFd;
 
     #[test]
     fn test_type_size() {
         assert_eq!(size_of::<Socket>(), 8);
     }
+
+    #[test]
+    fn test_poller_id() {
+        let state = state_with_pollers(2);
+        let typ = empty_process_type("A");
+        let process = new_process(*typ);
+        let output = UdpSocket::bind("0.0.0.0:0").unwrap();
+        let mut socket = socket(&output);
+
+        assert_eq!(socket.poller_id(), None);
+
+        socket.register(&state, *process, 1, Interest::Read);
+        assert_eq!(socket.poller_id(), Some(1));
+
+        socket.deregister(&state);
+        assert_eq!(socket.poller_id(), None);
+    }
+
+    fn state_with_pollers(amount: u8) -> RcState {
+        let mut config = Config::new();
+
+        config.netpoll_threads = amount;
+        config.netpoll_balance = 1;
+        State::new(config, Vec::new())
+    }
+
+    fn socket(output: &UdpSocket) -> Socket {
+        Socket {
+            inner: output.as_raw_fd(),
+            registered: AtomicI8::new(NOT_REGISTERED),
+        }
+    }
+
+    fn sockets(state: &State) -> Vec<usize> {
+        state
+            .network_poller_sockets
+            .iter()
+            .map(|v| v.load(Ordering::Acquire))
+            .collect()
+    }
+
+    #[test]
+    fn test_register_counts_sockets() {
+        let state = state_with_pollers(2);
+        let typ = empty_process_type("A");
+        let process = new_process(*typ);
+        let output = UdpSocket::bind("0.0.0.0:0").unwrap();
+        let mut socket = socket(&output);
+
+        socket.register(&state, *process, 1, Interest::Read);
+        socket.register(&state, *process, 1, Interest::Write);
+        assert_eq!(sockets(&state), vec![0, 1]);
+
+        socket.deregister(&state);
+        assert_eq!(sockets(&state), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_register_without_balancing() {
+        let mut config = Config::new();
+
+        config.netpoll_threads = 2;
+
+        let state = State::new(config, Vec::new());
+        let typ = empty_process_type("A");
+        let process = new_process(*typ);
+        let output = UdpSocket::bind("0.0.0.0:0").unwrap();
+        let mut socket = socket(&output);
+
+        socket.register(&state, *process, 1, Interest::Read);
+        assert_eq!(socket.poller_id(), Some(1));
+        assert_eq!(sockets(&state), vec![0, 0]);
+
+        socket.deregister(&state);
+        assert_eq!(sockets(&state), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_register_balanced() {
+        let state = state_with_pollers(3);
+        let typ = empty_process_type("A");
+        let process = new_process(*typ);
+        let outputs: Vec<_> =
+            (0..4).map(|_| UdpSocket::bind("0.0.0.0:0").unwrap()).collect();
+        let mut socks: Vec<_> = outputs.iter().map(socket).collect();
+
+        for sock in &mut socks {
+            sock.register_balanced(&state, *process, Interest::Read);
+        }
+
+        let ids: Vec<_> = socks.iter().map(|s| s.poller_id()).collect();
+
+        assert_eq!(ids, vec![Some(0), Some(1), Some(2), Some(0)]);
+        assert_eq!(sockets(&state), vec![2, 1, 1]);
+
+        socks[1].deregister(&state);
+        assert_eq!(sockets(&state), vec![2, 0, 1]);
+
+        socks[1].register_balanced(&state, *process, Interest::Read);
+        assert_eq!(socks[1].poller_id(), Some(1));
+        assert_eq!(sockets(&state), vec![2, 1, 1]);
+    }
+
+    #[test]
+    fn test_register_balanced_with_existing_registration() {
+        let state = state_with_pollers(2);
+        let typ = empty_process_type("A");
+        let process = new_process(*typ);
+        let output = UdpSocket::bind("0.0.0.0:0").unwrap();
+        let mut socket = socket(&output);
+
+        socket.register(&state, *process, 0, Interest::Read);
+        socket.register_balanced(&state, *process, Interest::Write);
+        assert_eq!(socket.poller_id(), Some(0));
+        assert_eq!(sockets(&state), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_release() {
+        let state = state_with_pollers(2);
+        let typ = empty_process_type("A");
+        let process = new_process(*typ);
+        let output = UdpSocket::bind("0.0.0.0:0").unwrap();
+        let mut socket = socket(&output);
+
+        socket.register(&state, *process, 1, Interest::Read);
+        socket.release(&state);
+        socket.release(&state);
+        assert_eq!(socket.poller_id(), None);
+        assert_eq!(sockets(&state), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_deregister() {
+        let state = setup();
+        let typ = empty_process_type("A");
+        let process = new_process(*typ);
+        let output = UdpSocket::bind("0.0.0.0:0").unwrap();
+        let mut socket = socket(&output);
+
+        socket.register(&state, *process, 0, Interest::Read);
+        assert_eq!(socket.registered.load(Ordering::Acquire), 0);
+
+        socket.deregister(&state);
+        assert_eq!(socket.registered.load(Ordering::Acquire), NOT_REGISTERED);
+    }
+
+    #[test]
+    fn test_deregister_twice() {
+        let state = setup();
+        let typ = empty_process_type("A");
+        let process = new_process(*typ);
+        let output = UdpSocket::bind("0.0.0.0:0").unwrap();
+        let mut socket = socket(&output);
+
+        socket.register(&state, *process, 0, Interest::Read);
+        socket.deregister(&state);
+        socket.deregister(&state);
+        assert_eq!(socket.registered.load(Ordering::Acquire), NOT_REGISTERED);
+    }
+
+    #[test]
+    fn test_deregister_without_registering() {
+        let state = setup();
+        let output = UdpSocket::bind("0.0.0.0:0").unwrap();
+        let mut socket = socket(&output);
+
+        socket.deregister(&state);
+        assert_eq!(socket.registered.load(Ordering::Acquire), NOT_REGISTERED);
+    }
 }
@@ -1,6 +1,13 @@
 use crate::memory_map::MemoryMap;
+use crate::process::ProcessPointer;
+use crate::runtime::process::PANIC_STATUS;
+use crate::scheduler::process::CURRENT_PROCESS;
 use rustix::param::page_size;
 use std::collections::VecDeque;
+use std::ffi::{c_int, c_void};
+use std::mem::{transmute, zeroed};
+use std::ptr::null_mut;
+use std::sync::OnceLock;
 
 /// The age of a reusable stack after which we deem it too old to keep around.
 ///
@@ -18,6 +25,17 @@ const SHRINK_AGE: u16 = 10;
 /// allocating stacks a bit.
 const MIN_STACKS: usize = 16;
 
+/// The size (in bytes) of the alternative signal stack used by every thread.
+const SIGNAL_STACK_SIZE: usize = 64 * 1024;
+
+/// The signal handlers that were installed before the stack overflow handler.
+///
+/// Faults that aren't the result of a process overflowing its stack are passed
+/// on to these handlers, such that handlers installed by e.g. C libraries
+/// continue to work.
+static PREVIOUS_SEGV_HANDLER: OnceLock<libc::sigaction> = OnceLock::new();
+static PREVIOUS_BUS_HANDLER: OnceLock<libc::sigaction> = OnceLock::new();
+
 pub(crate) fn total_stack_size(size: usize, page: usize) -> usize {
     // Round the user-provided size up to the nearest multiple of the page size.
     let rounded = (size + (page - 1)) & !(page - 1);
@@ -27,6 +45,166 @@ pub(crate) fn total_stack_size(size: usize, page: usize) -> usize {
     (page + page + rounded).next_power_of_two()
 }
 
+/// Returns `true` if `address` resides in the guard page of the stack that
+/// starts at `start`.
+fn is_guard_page(address: usize, start: usize, page: usize) -> bool {
+    address >= start + page && address < start + page + page
+}
+
+/// Returns the process running on the current thread, if there is one.
+///
+/// This only reads a thread-local that doesn't need to be initialized lazily,
+/// making it safe to use in a signal handler.
+fn current_process() -> Option<ProcessPointer> {
+    let ptr = CURRENT_PROCESS.try_with(|p| p.get()).unwrap_or(null_mut());
+
+    if ptr.is_null() {
+        None
+    } else {
+        Some(unsafe { ProcessPointer::new(ptr) })
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+unsafe fn fault_address(info: *mut libc::siginfo_t) -> usize {
+    (*info).si_addr() as usize
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+unsafe fn fault_address(info: *mut libc::siginfo_t) -> usize {
+    (*info).si_addr as usize
+}
+
+unsafe fn write_stderr(bytes: &[u8]) {
+    libc::write(libc::STDERR_FILENO, bytes.as_ptr() as _, bytes.len());
+}
+
+unsafe extern "C" fn overflow_handler(
+    signal: c_int,
+    info: *mut libc::siginfo_t,
+    context: *mut c_void,
+) {
+    let address = fault_address(info);
+
+    // The fault is only the result of a stack overflow if it occurs in the
+    // guard page of the stack of the process running on the current thread.
+    // Faults in the guard pages of other stacks (e.g. due to a dangling
+    // pointer) or in other memory are not stack overflows.
+    if let Some(process) =
+        current_process().filter(|p| !p.stack_pointer.is_null())
+    {
+        let start = process.stack.private_data_pointer() as usize;
+
+        if is_guard_page(address, start, page_size()) {
+            // We're running in a signal handler, so we can only use functions
+            // that are async-signal-safe. This means we can't use the regular
+            // panic logic, as it allocates memory.
+            write_stderr(b"Process '");
+            write_stderr(process.header.instance_of.name.as_bytes());
+            write_stderr(b"' panicked: the process overflowed its stack\n");
+            libc::_exit(PANIC_STATUS);
+        }
+    }
+
+    let previous = if signal == libc::SIGSEGV {
+        PREVIOUS_SEGV_HANDLER.get()
+    } else {
+        PREVIOUS_BUS_HANDLER.get()
+    };
+
+    if let Some(prev) = previous {
+        let handler = prev.sa_sigaction;
+
+        if handler != libc::SIG_DFL && handler != libc::SIG_IGN {
+            if prev.sa_flags & libc::SA_SIGINFO != 0 {
+                let func: unsafe extern "C" fn(
+                    c_int,
+                    *mut libc::siginfo_t,
+                    *mut c_void,
+                ) = transmute(handler);
+
+                func(signal, info, context);
+            } else {
+                let func: unsafe extern "C" fn(c_int) = transmute(handler);
+
+                func(signal);
+            }
+
+            return;
+        }
+    }
+
+    // The fault isn't the result of a stack overflow and there's no other
+    // handler to pass it on to. In this case we restore the default handler
+    // and return, causing the faulting instruction to be executed again, at
+    // which point the default handler terminates the program.
+    let mut action: libc::sigaction = zeroed();
+
+    action.sa_sigaction = libc::SIG_DFL;
+    libc::sigaction(signal, &action, null_mut());
+}
+
+/// Installs the stack overflow handler for the given signal, saving the
+/// handler that was installed before it.
+unsafe fn install_signal_handler(
+    signal: c_int,
+    previous: &OnceLock<libc::sigaction>,
+) {
+    let handler = overflow_handler as *const () as usize;
+    let mut old: libc::sigaction = zeroed();
+
+    libc::sigaction(signal, null_mut(), &mut old);
+
+    // The handler may already be installed, in which case we must not chain
+    // to ourselves.
+    if old.sa_sigaction != handler {
+        let _ = previous.set(old);
+    }
+
+    let mut action: libc::sigaction = zeroed();
+
+    action.sa_sigaction = handler;
+    action.sa_flags = libc::SA_SIGINFO | libc::SA_ONSTACK;
+    libc::sigemptyset(&mut action.sa_mask);
+    libc::sigaction(signal, &action, null_mut());
+}
+
+/// Installs a signal handler that detects processes overflowing their stacks.
+///
+/// Without this handler, a process overflowing its stack results in a
+/// segmentation fault without any indication as to what went wrong. With the
+/// handler in place, the program is instead terminated in the same way as a
+/// panic.
+///
+/// Any SIGSEGV or SIGBUS handlers installed before this handler are called for
+/// faults that aren't the result of a stack overflow.
+pub(crate) fn install_overflow_handler() {
+    unsafe {
+        install_signal_handler(libc::SIGSEGV, &PREVIOUS_SEGV_HANDLER);
+        install_signal_handler(libc::SIGBUS, &PREVIOUS_BUS_HANDLER);
+    }
+}
+
+/// Sets up an alternative signal stack for the current thread.
+///
+/// When a process overflows its stack there's no stack space left to run the
+/// overflow handler on, so the handler runs on a separate stack instead.
+///
+/// The memory of the signal stack is never released, as the threads that use
+/// it run until the program terminates.
+pub(crate) fn setup_signal_stack() {
+    let mem = Box::leak(vec![0_u8; SIGNAL_STACK_SIZE].into_boxed_slice());
+    let stack = libc::stack_t {
+        ss_sp: mem.as_mut_ptr() as _,
+        ss_flags: 0,
+        ss_size: SIGNAL_STACK_SIZE,
+    };
+
+    unsafe {
+        libc::sigaltstack(&stack, null_mut());
+    }
+}
+
 /// A pool of `Stack` objects to reuse.
 ///
 /// Stacks all share the same size and can't grow beyond this size. The decision
@@ -167,14 +345,15 @@ pub struct Stack {
 impl Stack {
     pub(crate) fn new(size: usize, page_size: usize) -> Self {
         let size = total_stack_size(size, page_size);
-        let mut mem = MemoryMap::stack(size);
-
         // There's nothing we can do at runtime in response to the guard page
-        // not being set up, so we just terminate if this ever happens.
-        mem.protect(page_size, page_size).expect(
-            "Failed to set up the stack's guard page. \
-            You may need to increase the number of memory map areas allowed",
-        );
+        // not being set up, so we just terminate if this ever happens. The
+        // guard page comes after the private page, as the latter must remain
+        // readable and writable.
+        let mem = MemoryMap::stack_with_guard(size, page_size, page_size)
+            .expect(
+                "Failed to set up the stack's guard page. \
+                You may need to increase the number of memory map areas allowed",
+            );
 
         // Because the stack is managed using mmap, its memory is only allocated
         // on demand. When starting a process we need to write some data to the
@@ -205,6 +384,41 @@ impl Stack {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_guard_page() {
+        let page = page_size();
+        let size = total_stack_size(page, page);
+        let stack = Stack::new(page, page);
+        let start = stack.private_data_pointer() as usize;
+
+        assert!(!is_guard_page(start, start, page));
+        assert!(!is_guard_page(start + page - 1, start, page));
+        assert!(is_guard_page(start + page, start, page));
+        assert!(is_guard_page(start + page + page - 1, start, page));
+        assert!(!is_guard_page(start + page + page, start, page));
+        assert!(!is_guard_page(start + size - 1, start, page));
+
+        // The guard pages of other stacks don't belong to this stack.
+        assert!(!is_guard_page(start + size + page, start, page));
+        assert!(!is_guard_page(page, start, page));
+    }
+
+    #[test]
+    fn test_current_process_without_a_process() {
+        assert!(current_process().is_none());
+    }
+
+    #[test]
+    fn test_install_overflow_handler_twice() {
+        install_overflow_handler();
+        install_overflow_handler();
+
+        let handler = overflow_handler as *const () as usize;
+
+        assert_ne!(PREVIOUS_SEGV_HANDLER.get().unwrap().sa_sigaction, handler);
+        assert_ne!(PREVIOUS_BUS_HANDLER.get().unwrap().sa_sigaction, handler);
+    }
+
     #[test]
     fn test_stack_pool_alloc() {
         let mut pool = StackPool::new(page_size());
@@ -9,7 +9,7 @@ use std::collections::HashMap;
 use std::env;
 use std::hash::{BuildHasher, Hasher};
 use std::panic::RefUnwindSafe;
-use std::sync::atomic::AtomicU32;
+use std::sync::atomic::{AtomicU32, AtomicUsize};
 use std::thread::available_parallelism;
 use std::time;
 
@@ -103,6 +103,12 @@ pub struct State {
     /// The network pollers to use for process threads.
     pub(crate) network_pollers: Vec<NetworkPoller>,
 
+    /// The number of sockets registered with each network poller.
+    ///
+    /// This is used to pick the least loaded poller when registering a socket
+    /// with balancing enabled.
+    pub(crate) network_poller_sockets: Vec<AtomicUsize>,
+
     pub(crate) signals: Signals,
 }
 
@@ -122,6 +128,8 @@ impl State {
 
         let network_pollers =
             (0..config.netpoll_threads).map(|_| NetworkPoller::new()).collect();
+        let network_poller_sockets =
+            (0..config.netpoll_threads).map(|_| AtomicUsize::new(0)).collect();
 
         let state = State {
             hash_key0,
@@ -135,6 +143,7 @@ impl State {
             timeout_worker: TimeoutWorker::new(),
             arguments,
             network_pollers,
+            network_poller_sockets,
             signals: Signals::new(),
         };
 
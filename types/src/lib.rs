@@ -119,6 +119,10 @@ const METHODS_LIMIT: usize = (u32::MAX - 1) as usize;
 /// instead of the symbol.
 pub const IMPORT_MODULE_ITSELF_NAME: &str = "self";
 
+/// When a symbol is using this name, all symbols defined in the source module
+/// should be imported.
+pub const IMPORT_ALL_NAME: &str = "*";
+
 /// The maximum nesting to allow when verifying types.
 const MAX_VERIFY_DEPTH: usize = 64;
 
@@ -2393,6 +2397,12 @@ pub enum Intrinsic {
     MutMove,
     IntLeadingZeros,
     IntTrailingZeros,
+    IntCountOnes,
+    FloatSqrt,
+    FloatPow,
+    FloatSin,
+    FloatCos,
+    FloatLog,
 }
 
 impl Intrinsic {
@@ -2451,6 +2461,12 @@ impl Intrinsic {
             Intrinsic::MutMove,
             Intrinsic::IntLeadingZeros,
             Intrinsic::IntTrailingZeros,
+            Intrinsic::IntCountOnes,
+            Intrinsic::FloatSqrt,
+            Intrinsic::FloatPow,
+            Intrinsic::FloatSin,
+            Intrinsic::FloatCos,
+            Intrinsic::FloatLog,
         ]
         .into_iter()
         .fold(HashMap::new(), |mut map, func| {
@@ -2514,6 +2530,12 @@ impl Intrinsic {
             Intrinsic::MutMove => "mut_move",
             Intrinsic::IntLeadingZeros => "int_leading_zeros",
             Intrinsic::IntTrailingZeros => "int_trailing_zeros",
+            Intrinsic::IntCountOnes => "int_count_ones",
+            Intrinsic::FloatSqrt => "float_sqrt",
+            Intrinsic::FloatPow => "float_pow",
+            Intrinsic::FloatSin => "float_sin",
+            Intrinsic::FloatCos => "float_cos",
+            Intrinsic::FloatLog => "float_log",
         }
     }
 
@@ -2580,6 +2602,12 @@ impl Intrinsic {
             Intrinsic::MutMove => arguments[0].as_mut(db),
             Intrinsic::IntLeadingZeros => TypeRef::int(),
             Intrinsic::IntTrailingZeros => TypeRef::int(),
+            Intrinsic::IntCountOnes => TypeRef::int(),
+            Intrinsic::FloatSqrt => TypeRef::float(),
+            Intrinsic::FloatPow => TypeRef::float(),
+            Intrinsic::FloatSin => TypeRef::float(),
+            Intrinsic::FloatCos => TypeRef::float(),
+            Intrinsic::FloatLog => TypeRef::float(),
         }
     }
 }
@@ -3464,6 +3492,9 @@ impl Symbol {
 struct ModuleSymbol {
     symbol: Symbol,
     used: bool,
+
+    /// The source locations at which the symbol is referred to.
+    references: HashSet<Location>,
 }
 
 /// An Inko module.
@@ -3573,6 +3604,28 @@ impl ModuleId {
         }
     }
 
+    /// Looks up a symbol referred to at the given source location, and marks it
+    /// as used.
+    ///
+    /// Unlike `use_symbol()`, this also records the location such that looking
+    /// up the same reference multiple times only counts as a single use.
+    pub fn use_symbol_at(
+        self,
+        db: &mut Database,
+        name: &str,
+        location: Location,
+    ) -> Option<Symbol> {
+        let module = self.get_mut(db);
+
+        if let Some(sym) = module.symbols.get_mut(name) {
+            sym.used = true;
+            sym.references.insert(location);
+            Some(sym.symbol)
+        } else {
+            None
+        }
+    }
+
     fn symbol(self, db: &Database, name: &str) -> Option<Symbol> {
         self.get(db).symbols.get(name).map(|v| v.symbol)
     }
@@ -3581,6 +3634,12 @@ impl ModuleId {
         self.get(db).symbols.get(name).map_or(false, |v| v.used)
     }
 
+    /// Returns the number of source references to a symbol, as recorded using
+    /// `use_symbol_at()`.
+    pub fn symbol_uses(self, db: &Database, name: &str) -> usize {
+        self.get(db).symbols.get(name).map_or(0, |v| v.references.len())
+    }
+
     pub fn symbols(self, db: &Database) -> Vec<(String, Symbol)> {
         self.get(db)
             .symbols
@@ -3616,10 +3675,47 @@ impl ModuleId {
         }
     }
 
-    pub fn new_symbol(self, db: &mut Database, name: String, symbol: Symbol) {
-        self.get_mut(db)
+    /// Returns the symbols defined by this module that can be imported, sorted
+    /// by their names.
+    ///
+    /// Unlike `import_symbol()`, this method doesn't mark the symbols as used,
+    /// and symbols imported into this module from other modules are excluded.
+    pub fn importable_symbols(self, db: &Database) -> Vec<(String, Symbol)> {
+        let mut symbols: Vec<_> = self
+            .get(db)
             .symbols
-            .insert(name, ModuleSymbol { symbol, used: false });
+            .iter()
+            .filter(|(name, value)| {
+                // Generated symbol names start with "$", which we never want to
+                // include.
+                if name.starts_with('$') {
+                    return false;
+                }
+
+                let module_id = match value.symbol {
+                    Symbol::Type(id) => id.module(db),
+                    Symbol::Trait(id) => id.module(db),
+                    Symbol::Constant(id) => id.module(db),
+                    Symbol::Method(id) => id.module(db),
+                    Symbol::Module(_) | Symbol::TypeParameter(_) => {
+                        return false
+                    }
+                };
+
+                module_id == self
+            })
+            .map(|(name, value)| (name.clone(), value.symbol))
+            .collect();
+
+        symbols.sort_by(|a, b| a.0.cmp(&b.0));
+        symbols
+    }
+
+    pub fn new_symbol(self, db: &mut Database, name: String, symbol: Symbol) {
+        self.get_mut(db).symbols.insert(
+            name,
+            ModuleSymbol { symbol, used: false, references: HashSet::new() },
+        );
     }
 
     pub fn method(self, db: &Database, name: &str) -> Option<MethodId> {
@@ -6562,6 +6658,53 @@ mod tests {
         assert!(id.get(&db).symbols["A"].used);
     }
 
+    #[test]
+    fn test_module_id_symbol_is_used() {
+        let mut db = Database::new();
+        let id = Module::alloc(
+            &mut db,
+            ModuleName::new("foo"),
+            PathBuf::from("test.inko"),
+        );
+
+        id.new_symbol(&mut db, "A".to_string(), Symbol::Module(id));
+        id.new_symbol(&mut db, "B".to_string(), Symbol::Module(id));
+        id.use_symbol(&mut db, "A");
+
+        assert!(id.symbol_is_used(&db, "A"));
+        assert!(!id.symbol_is_used(&db, "B"));
+        assert!(!id.symbol_is_used(&db, "C"));
+    }
+
+    #[test]
+    fn test_module_id_symbol_uses() {
+        let mut db = Database::new();
+        let id = Module::alloc(
+            &mut db,
+            ModuleName::new("foo"),
+            PathBuf::from("test.inko"),
+        );
+        let loc1 = Location::new(&(1..=1), &(1..=1));
+        let loc2 = Location::new(&(2..=2), &(1..=1));
+
+        id.new_symbol(&mut db, "A".to_string(), Symbol::Module(id));
+        id.new_symbol(&mut db, "B".to_string(), Symbol::Module(id));
+
+        assert_eq!(
+            id.use_symbol_at(&mut db, "A", loc1),
+            Some(Symbol::Module(id))
+        );
+        id.use_symbol_at(&mut db, "A", loc1);
+        id.use_symbol_at(&mut db, "A", loc2);
+        id.use_symbol(&mut db, "B");
+
+        assert_eq!(id.symbol_uses(&db, "A"), 2);
+        assert_eq!(id.symbol_uses(&db, "B"), 0);
+        assert_eq!(id.symbol_uses(&db, "C"), 0);
+        assert!(id.symbol_is_used(&db, "A"));
+        assert!(id.symbol_is_used(&db, "B"));
+    }
+
     #[test]
     fn test_module_id_import_symbol() {
         let mut db = Database::new();
@@ -6643,6 +6786,47 @@ mod tests {
         assert_eq!(foo.import_symbol(&mut db, "fizz"), None);
     }
 
+    #[test]
+    fn test_module_id_importable_symbols() {
+        let mut db = Database::new();
+        let foo = new_module(&mut db, "foo");
+        let bar = new_module(&mut db, "bar");
+        let typ = new_type(&mut db, "A");
+        let other = new_type(&mut db, "B");
+        let constant = Constant::alloc(
+            &mut db,
+            foo,
+            Location::default(),
+            "C".to_string(),
+            Visibility::Private,
+            TypeRef::Unknown,
+        );
+        let type_param = TypeParameter::alloc(&mut db, "D".to_string());
+
+        typ.set_module(&mut db, foo);
+        other.set_module(&mut db, bar);
+        foo.new_symbol(&mut db, "C".to_string(), Symbol::Constant(constant));
+        foo.new_symbol(&mut db, "A".to_string(), Symbol::Type(typ));
+        foo.new_symbol(&mut db, "B".to_string(), Symbol::Type(other));
+        foo.new_symbol(
+            &mut db,
+            "D".to_string(),
+            Symbol::TypeParameter(type_param),
+        );
+        foo.new_symbol(&mut db, "bar".to_string(), Symbol::Module(bar));
+        foo.new_symbol(&mut db, "$A".to_string(), Symbol::Type(typ));
+
+        assert_eq!(
+            foo.importable_symbols(&db),
+            vec![
+                ("A".to_string(), Symbol::Type(typ)),
+                ("C".to_string(), Symbol::Constant(constant)),
+            ]
+        );
+        assert!(!foo.symbol_is_used(&db, "A"));
+        assert!(bar.importable_symbols(&db).is_empty());
+    }
+
     #[test]
     fn test_module_id_symbols() {
         let mut db = Database::new();